@@ -0,0 +1,87 @@
+use untrusted::{Input, Reader};
+
+use crate::{Error, ReaderExt};
+
+/// A reader of bit fields packed across byte boundaries.
+///
+/// Bits are read most significant bit first, which is the order used by
+/// network protocols like the IPv4 header.
+///
+/// # Example
+///
+/// ```
+/// use untrusted::{Input, Reader};
+/// use untrustended::{BitReader, Error};
+///
+/// // First byte of an IPv4 header: version 4 and header length of 5 words.
+/// let buf = [0x45];
+/// let mut bits = BitReader::new(&buf);
+/// assert_eq!(bits.read_bits(4), Ok(4));
+/// assert_eq!(bits.read_bits(4), Ok(5));
+/// assert_eq!(bits.read_bits(1), Err(Error::EndOfInput));
+/// ```
+pub struct BitReader<'a> {
+    bytes: &'a [u8],
+    position: usize,
+}
+
+impl<'a> BitReader<'a> {
+    /// Construct a new BitReader for the given bytes.
+    #[inline]
+    pub fn new(bytes: &'a [u8]) -> Self {
+        Self { bytes, position: 0 }
+    }
+
+    /// Construct a new BitReader for the next `num_bytes` bytes of `input`.
+    ///
+    /// Returns Ok(v) where v is the BitReader, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
+    #[inline]
+    pub fn from_reader(input: &mut Reader<'a>, num_bytes: usize) -> Result<Self, Error> {
+        input.read_bytes_less_safe(num_bytes).map(Self::new)
+    }
+
+    /// Reads `n` bits, at most 64, as unsigned integer.
+    ///
+    /// Nothing is consumed if fewer than `n` bits remain.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading, or
+    /// Err(Error::ParseError) if `n` is greater than 64.
+    pub fn read_bits(&mut self, n: u8) -> Result<u64, Error> {
+        if n > 64 {
+            return Err(Error::ParseError);
+        }
+        let end = self.position + usize::from(n);
+        if end > self.bytes.len() * 8 {
+            return Err(Error::EndOfInput);
+        }
+        let mut value = 0u64;
+        for i in self.position..end {
+            let bit = (self.bytes[i / 8] >> (7 - i % 8)) & 1;
+            value = (value << 1) | u64::from(bit);
+        }
+        self.position = end;
+        Ok(value)
+    }
+
+    /// Discards the rest of the current byte, so the next read starts at a
+    /// byte boundary. Does nothing if already at a byte boundary.
+    #[inline]
+    pub fn align(&mut self) {
+        self.position = self.position.div_ceil(8) * 8;
+    }
+
+    /// Returns `true` if all bits have been read, and `false` otherwise.
+    #[inline]
+    pub fn at_end(&self) -> bool {
+        self.position == self.bytes.len() * 8
+    }
+}
+
+impl<'a> From<Input<'a>> for BitReader<'a> {
+    #[inline]
+    fn from(input: Input<'a>) -> Self {
+        Self::new(input.as_slice_less_safe())
+    }
+}
@@ -0,0 +1,25 @@
+//! Checksums used to verify frames read from untrusted input.
+
+/// Calculates CRC-16/CCITT of `data`.
+///
+/// Uses the polynomial `0x1021` with initial value `0xFFFF`, no reflection
+/// and no final XOR (also known as CRC-16/CCITT-FALSE). The CRC of
+/// `b"123456789"` is `0x29B1`.
+///
+/// ```
+/// use untrustended::crc;
+///
+/// assert_eq!(crc::crc16_ccitt(b"123456789"), 0x29B1);
+/// ```
+pub fn crc16_ccitt(data: &[u8]) -> u16 {
+    const POLY: u16 = 0x1021;
+    data.iter().fold(0xFFFF, |crc, &byte| {
+        (0..8).fold(crc ^ (u16::from(byte) << 8), |crc, _| {
+            if crc & 0x8000 != 0 {
+                (crc << 1) ^ POLY
+            } else {
+                crc << 1
+            }
+        })
+    })
+}
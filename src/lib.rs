@@ -37,15 +37,103 @@
 //!     input.read_all(Error::UnknownError, read_stuff).expect("read_all to succeed");
 //! }
 //! ```
+//!
+//! # Features
+//!
+//! - Without default features the crate is `no_std` and needs neither `std`
+//!   nor an allocator.
+//! - `alloc` enables methods returning `Vec`, `String` or `Cow`, and the
+//!   [writer] module. The crate stays `no_std`.
+//! - `use_std` (default) implies `alloc` and additionally enables methods
+//!   using `std` only types like `Ipv4Addr` and `Duration`.
+//! - `derive` enables `#[derive(Readable)]`.
 
 #![cfg_attr(not(feature = "use_std"), no_std)]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+use core::num::NonZeroU64;
 use untrusted::{EndOfInput, Input, Reader};
 
-pub use crate::error::Error;
+use crate::sealed::Cursor;
 
+pub use crate::bits::BitReader;
+pub use crate::error::{Error, OffsetError};
+pub use crate::tracking::TrackingReader;
+/// Derive macro generating an implementation of the trait [Readable].
+///
+/// Available with the `derive` feature.
+#[cfg(feature = "derive")]
+pub use untrustended_derive::Readable;
+
+mod bits;
+pub mod crc;
+pub mod msgpack;
+pub mod protobuf;
+pub mod riff;
+pub mod tar;
+pub mod thrift;
+mod tracking;
+mod varint;
+#[cfg(feature = "alloc")]
+pub mod writer;
+
+#[cfg(feature = "alloc")]
+use alloc::{borrow::Cow, borrow::ToOwned, string::String, vec::Vec};
 #[cfg(feature = "use_std")]
-use std::net::{Ipv4Addr, Ipv6Addr};
+use std::net::{Ipv4Addr, Ipv6Addr, SocketAddrV4, SocketAddrV6};
+#[cfg(feature = "use_std")]
+use std::time::Duration;
+
+/// Byte order of a value selected at runtime.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Endianness {
+    /// Most significant byte first.
+    Big,
+    /// Least significant byte first.
+    Little,
+}
+
+/// Rounding used when a value read has more precision than the result type.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum RoundingMode {
+    /// Round to the nearest representable value, ties to even.
+    Nearest,
+    /// Round towards zero.
+    Truncate,
+}
+
+/// Encoding of negative numbers in signed varints read by
+/// [ReaderExt::read_signed_varint].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SignedVarintMode {
+    /// Two's complement sign-extended LEB128 as in DWARF and WebAssembly.
+    /// -1 is encoded as `0x7F`.
+    Sleb128,
+    /// Zigzag encoded unsigned LEB128 as in Protocol Buffers `sint64`. -1 is
+    /// encoded as `0x01`.
+    Zigzag,
+}
+
+/// A byte of up to eight flags read by [ReaderExt::read_flag_byte].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct FlagByte(pub u8);
+
+impl FlagByte {
+    /// Returns the flag at index `bit`, most significant bit first like in
+    /// [ReaderExt::read_packed_bools]: bit 0 is `0x80` and bit 7 is `0x01`.
+    /// Indices past 7 return `false`.
+    #[inline]
+    pub fn get(self, bit: u32) -> bool {
+        0x80u8
+            .checked_shr(bit)
+            .is_some_and(|mask| self.0 & mask != 0)
+    }
+}
+
+/// Function reading one value of a column for [ReaderExt::read_soa].
+pub type ColumnReader = for<'r> fn(&mut Reader<'r>) -> Result<u64, Error>;
 
 /// A trait extending [untrusted](https://crates.io/crates/untrusted)'s
 /// [`Reader`](https://briansmith.org/rustdoc/untrusted/struct.Reader.html).
@@ -82,7 +170,7 @@ pub trait ReaderExt<'a> {
     /// the Reader encountered an end of the input while reading.
     #[inline]
     fn read_u16be(&mut self) -> Result<u16, Error> {
-        self.read_be().map_err(From::from)
+        self.read_be()
     }
 
     /// Reads 24 bit unsigned integer in big endian.
@@ -105,7 +193,7 @@ pub trait ReaderExt<'a> {
     /// the Reader encountered an end of the input while reading.
     #[inline]
     fn read_u32be(&mut self) -> Result<u32, Error> {
-        self.read_be().map_err(From::from)
+        self.read_be()
     }
 
     /// Reads 48 bit unsigned integer in big endian.
@@ -128,7 +216,7 @@ pub trait ReaderExt<'a> {
     /// the Reader encountered an end of the input while reading.
     #[inline]
     fn read_u64be(&mut self) -> Result<u64, Error> {
-        self.read_be().map_err(From::from)
+        self.read_be()
     }
 
     /// Reads 128 bit unsigned integer in big endian.
@@ -137,7 +225,7 @@ pub trait ReaderExt<'a> {
     /// the Reader encountered an end of the input while reading.
     #[inline]
     fn read_u128be(&mut self) -> Result<u128, Error> {
-        self.read_be().map_err(From::from)
+        self.read_be()
     }
 
     /// Reads 16 bit unsigned integer in little endian.
@@ -146,7 +234,7 @@ pub trait ReaderExt<'a> {
     /// the Reader encountered an end of the input while reading.
     #[inline]
     fn read_u16le(&mut self) -> Result<u16, Error> {
-        self.read_le().map_err(From::from)
+        self.read_le()
     }
 
     /// Reads 24 bit unsigned integer in little endian.
@@ -169,7 +257,7 @@ pub trait ReaderExt<'a> {
     /// the Reader encountered an end of the input while reading.
     #[inline]
     fn read_u32le(&mut self) -> Result<u32, Error> {
-        self.read_le().map_err(From::from)
+        self.read_le()
     }
 
     /// Reads 48 bit unsigned integer in little endian.
@@ -192,7 +280,7 @@ pub trait ReaderExt<'a> {
     /// the Reader encountered an end of the input while reading.
     #[inline]
     fn read_u64le(&mut self) -> Result<u64, Error> {
-        self.read_le().map_err(From::from)
+        self.read_le()
     }
 
     /// Reads 128 bit unsigned integer in little endian.
@@ -201,7 +289,31 @@ pub trait ReaderExt<'a> {
     /// the Reader encountered an end of the input while reading.
     #[inline]
     fn read_u128le(&mut self) -> Result<u128, Error> {
-        self.read_le().map_err(From::from)
+        self.read_le()
+    }
+
+    /// Reads boolean stored as one byte which must be 0 or 1.
+    ///
+    /// Returns Ok(v) where v is `false` for 0 and `true` for 1, or
+    /// Err(Error::EndOfInput) if the Reader is at the end of the input, or
+    /// Err(Error::InvalidValue) if the byte is neither 0 nor 1.
+    #[inline]
+    fn read_bool(&mut self) -> Result<bool, Error> {
+        match self.read_u8()? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(Error::InvalidValue),
+        }
+    }
+
+    /// Reads boolean stored as one byte where any nonzero value is true, as
+    /// in the C ABI.
+    ///
+    /// Returns Ok(v) where v is `false` for 0 and `true` otherwise, or
+    /// Err(Error::EndOfInput) if the Reader is at the end of the input.
+    #[inline]
+    fn read_bool_lenient(&mut self) -> Result<bool, Error> {
+        Ok(self.read_u8()? != 0)
     }
 
     /// Reads 8 bit signed integer.
@@ -219,13 +331,14 @@ pub trait ReaderExt<'a> {
     /// the Reader encountered an end of the input while reading.
     #[inline]
     fn read_i16be(&mut self) -> Result<i16, Error> {
-        self.read_be().map_err(From::from)
+        self.read_be()
     }
 
     /// Reads 24 bit signed integer in big endian.
     ///
     /// This method reads three bytes, but returns `i32` because Rust doesn't
-    /// have 24 bit integer type.
+    /// have 24 bit integer type. The value is sign extended, so every three
+    /// bytes read give a value in range [-2^23, 2^23 - 1].
     ///
     /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
     /// the Reader encountered an end of the input while reading.
@@ -236,13 +349,32 @@ pub trait ReaderExt<'a> {
         Ok((b1 << 8) + b2)
     }
 
+    /// Reads 24 bit signed integer stored in four bytes in big endian.
+    ///
+    /// Some formats store 24 bit values padded to 32 bits. The padding byte
+    /// must be the sign extension of the value: 0x00 for a positive value
+    /// and 0xFF for a negative one.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading, or
+    /// Err(Error::InvalidValue) if the padding byte isn't the sign extension
+    /// of the 24 bit value.
+    #[inline]
+    fn read_i32be_from_i24(&mut self) -> Result<i32, Error> {
+        let value = self.read_i32be()?;
+        if !(-0x80_0000..0x80_0000).contains(&value) {
+            return Err(Error::InvalidValue);
+        }
+        Ok(value)
+    }
+
     /// Reads 32 bit signed integer in big endian.
     ///
     /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
     /// the Reader encountered an end of the input while reading.
     #[inline]
     fn read_i32be(&mut self) -> Result<i32, Error> {
-        self.read_be().map_err(From::from)
+        self.read_be()
     }
 
     /// Reads 48 bit signed integer in big endian.
@@ -265,7 +397,7 @@ pub trait ReaderExt<'a> {
     /// the Reader encountered an end of the input while reading.
     #[inline]
     fn read_i64be(&mut self) -> Result<i64, Error> {
-        self.read_be().map_err(From::from)
+        self.read_be()
     }
 
     /// Reads 128 bit signed integer in big endian.
@@ -274,7 +406,7 @@ pub trait ReaderExt<'a> {
     /// the Reader encountered an end of the input while reading.
     #[inline]
     fn read_i128be(&mut self) -> Result<i128, Error> {
-        self.read_be().map_err(From::from)
+        self.read_be()
     }
 
     /// Reads 16 bit signed integer in little endian.
@@ -283,7 +415,7 @@ pub trait ReaderExt<'a> {
     /// the Reader encountered an end of the input while reading.
     #[inline]
     fn read_i16le(&mut self) -> Result<i16, Error> {
-        self.read_le().map_err(From::from)
+        self.read_le()
     }
 
     /// Reads 24 bit signed integer in little endian.
@@ -306,7 +438,7 @@ pub trait ReaderExt<'a> {
     /// the Reader encountered an end of the input while reading.
     #[inline]
     fn read_i32le(&mut self) -> Result<i32, Error> {
-        self.read_le().map_err(From::from)
+        self.read_le()
     }
 
     /// Reads 48 bit signed integer in little endian.
@@ -329,7 +461,7 @@ pub trait ReaderExt<'a> {
     /// the Reader encountered an end of the input while reading.
     #[inline]
     fn read_i64le(&mut self) -> Result<i64, Error> {
-        self.read_le().map_err(From::from)
+        self.read_le()
     }
 
     /// Reads 128 bit signed integer in little endian.
@@ -338,227 +470,2437 @@ pub trait ReaderExt<'a> {
     /// the Reader encountered an end of the input while reading.
     #[inline]
     fn read_i128le(&mut self) -> Result<i128, Error> {
-        self.read_le().map_err(From::from)
+        self.read_le()
     }
 
-    /// Reads given amount of bytes.
-    ///
-    /// Access the given amount of bytes as a slice so it can be processed by
-    /// functions that are not written using the untrusted's Input/Reader
-    /// framework.
+    /// Reads 32 bit IEEE 754 floating point number in big endian.
     ///
-    /// Returns Ok(v) where v is a `&[u8]` of bytes read, or
-    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
-    /// while reading.
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
     #[inline]
-    fn read_bytes_less_safe(&mut self, num_bytes: usize) -> Result<&'a [u8], Error> {
-        Ok(self.read_bytes(num_bytes).map(|v| v.as_slice_less_safe())?)
+    fn read_f32be(&mut self) -> Result<f32, Error> {
+        self.read_be()
     }
 
-    /// Reads bytes as UTF-8 String.
-    ///
-    /// Length required is the amount of bytes to read, not the amount of UTF-8
-    /// characters.
-    ///
-    /// Read bytes are validated to be valid UTF-8 by
-    /// [`str::from_utf8`](https://doc.rust-lang.org/std/str/fn.from_utf8.html)
-    /// method.
+    /// Reads 32 bit IEEE 754 floating point number in little endian.
     ///
-    /// Returns Ok(v) where v is a `&str` of bytes read, or
-    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
-    /// while reading, or Err(Error::ParseError) if UTF-8 parsing failed.
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
     #[inline]
-    #[cfg(feature = "use_std")]
-    fn read_utf8(&mut self, num_bytes: usize) -> Result<&'a str, Error> {
-        let buf = self.read_bytes_less_safe(num_bytes)?;
-        std::str::from_utf8(buf).map_err(From::from)
+    fn read_f32le(&mut self) -> Result<f32, Error> {
+        self.read_le()
     }
 
-    /// Reads bytes as UTF-16 String.
+    /// Reads 64 bit IEEE 754 floating point number in big endian.
     ///
-    /// Length is the amount of bytes to read, not the amount of UTF-16
-    /// characters. Length should be even number and Err(Error::ParseError) is
-    /// returned if it's odd.
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
+    #[inline]
+    fn read_f64be(&mut self) -> Result<f64, Error> {
+        self.read_be()
+    }
+
+    /// Reads 64 bit IEEE 754 floating point number in little endian.
     ///
-    /// Read bytes are validated to be valid UTF-16 by
-    /// [`String::from_utf16`](https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf16)
-    /// method.
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
+    #[inline]
+    fn read_f64le(&mut self) -> Result<f64, Error> {
+        self.read_le()
+    }
+
+    /// Reads a value in byte order selected at runtime, for example by a
+    /// byte order mark at the beginning of a message.
     ///
-    /// Returns Ok(v) where v is a `String` of bytes read, or
-    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
-    /// while reading, or Err(Error::ParseError) if UTF-8 parsing failed.
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
     #[inline]
-    #[cfg(feature = "use_std")]
-    fn read_utf16(&mut self, num_bytes: usize) -> Result<String, Error> {
-        if (num_bytes % 2) != 0 {
-            return Err(Error::ParseError);
-        }
-        let len16 = num_bytes / 2;
-        let mut buf: Vec<u16> = Vec::with_capacity(len16);
-        for _ in 0..len16 {
-            let b = self.read_u16be()?;
-            buf.push(b);
+    fn read_endian<T: FromReader>(&mut self, endian: Endianness) -> Result<T, Error> {
+        match endian {
+            Endianness::Big => self.read_be(),
+            Endianness::Little => self.read_le(),
         }
-        String::from_utf16(&buf).map_err(From::from)
     }
 
-    /// Reads IPv4 address in big endian format.
+    /// Reads 16 bit unsigned integer in byte order selected at runtime.
     ///
-    /// Returns Ok(v) where v is a `Ipv4Addr`, or Err(Error::EndOfInput) if the
-    /// Reader encountered an end of the input while reading, or
-    /// Err(Error::ParseError) if parsing of address failed.
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
     #[inline]
-    #[cfg(feature = "use_std")]
-    fn read_ipv4addr(&mut self) -> Result<Ipv4Addr, Error> {
-        self.read_u32be().map(Ipv4Addr::from)
+    fn read_u16(&mut self, endian: Endianness) -> Result<u16, Error> {
+        self.read_endian(endian)
     }
 
-    /// Reads IPv6 address in big endian format.
+    /// Reads 32 bit unsigned integer in byte order selected at runtime.
     ///
-    /// Returns Ok(v) where v is a `Ipv6Addr`, or Err(Error::EndOfInput) if the
-    /// Reader encountered an end of the input while reading, or
-    /// Err(Error::ParseError) if parsing of address failed.
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
     #[inline]
-    #[cfg(feature = "use_std")]
-    fn read_ipv6addr(&mut self) -> Result<Ipv6Addr, Error> {
-        self.read_u128be().map(Ipv6Addr::from)
+    fn read_u32(&mut self, endian: Endianness) -> Result<u32, Error> {
+        self.read_endian(endian)
     }
-}
 
-impl<'a> ReaderExt<'a> for Reader<'a> {
+    /// Reads 64 bit unsigned integer in byte order selected at runtime.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
     #[inline]
-    fn read_byte(&mut self) -> Result<u8, EndOfInput> {
-        self.read_byte()
+    fn read_u64(&mut self, endian: Endianness) -> Result<u64, Error> {
+        self.read_endian(endian)
     }
 
+    /// Reads 128 bit unsigned integer in byte order selected at runtime.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
     #[inline]
-    fn read_bytes(&mut self, num_bytes: usize) -> Result<Input<'a>, EndOfInput> {
-        self.read_bytes(num_bytes)
+    fn read_u128(&mut self, endian: Endianness) -> Result<u128, Error> {
+        self.read_endian(endian)
     }
 
+    /// Reads 16 bit signed integer in byte order selected at runtime.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
     #[inline]
-    fn read_be<T: FromReader>(&mut self) -> Result<T, Error> {
-        FromReader::read_be(self)
+    fn read_i16(&mut self, endian: Endianness) -> Result<i16, Error> {
+        self.read_endian(endian)
     }
 
+    /// Reads 32 bit signed integer in byte order selected at runtime.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
     #[inline]
-    fn read_le<T: FromReader>(&mut self) -> Result<T, Error> {
-        FromReader::read_le(self)
+    fn read_i32(&mut self, endian: Endianness) -> Result<i32, Error> {
+        self.read_endian(endian)
     }
-}
-
-/// A trait to abstract the idea of creating a new instance of a type from
-/// reading bytes out from `Reader`.
-pub trait FromReader: Sized {
-    /// Read as many bytes as needed to instantiate a type in Big Endian byte
-    /// order.
-    fn read_be(_: &mut Reader<'_>) -> Result<Self, Error>;
-
-    /// Read as many bytes as needed to instantiate a type in Little Endian byte
-    /// order.
-    fn read_le(_: &mut Reader<'_>) -> Result<Self, Error>;
-}
-
-macro_rules! read_unsigned {
-    ($type:ty) => {
-        #[inline]
-        fn read_be(reader: &mut Reader<'_>) -> Result<Self, Error> {
-            const LEN: usize = core::mem::size_of::<$type>();
-            let mut arr = [0u8; LEN];
-            let slice = reader.read_bytes(LEN)?.as_slice_less_safe();
-            arr.copy_from_slice(slice);
-            Ok(<$type>::from_be_bytes(arr))
-        }
-
-        #[inline]
-        fn read_le(reader: &mut Reader<'_>) -> Result<Self, Error> {
-            const LEN: usize = core::mem::size_of::<$type>();
-            let mut arr = [0u8; LEN];
-            let slice = reader.read_bytes(LEN)?.as_slice_less_safe();
-            arr.copy_from_slice(slice);
-            Ok(<$type>::from_le_bytes(arr))
-        }
-    };
-}
-
-macro_rules! read_signed {
-    ($type:ty) => {
-        #[inline]
-        fn read_be(reader: &mut Reader<'_>) -> Result<Self, Error> {
-            let r = reader.read_be::<$type>()?;
-            Ok(r as Self)
-        }
-
-        #[inline]
-        fn read_le(reader: &mut Reader<'_>) -> Result<Self, Error> {
-            let r = reader.read_le::<$type>()?;
-            Ok(r as Self)
-        }
-    };
-}
 
-impl FromReader for u8 {
+    /// Reads 64 bit signed integer in byte order selected at runtime.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
     #[inline]
-    fn read_be(reader: &mut Reader<'_>) -> Result<Self, Error> {
-        reader.read_byte().map_err(From::from)
+    fn read_i64(&mut self, endian: Endianness) -> Result<i64, Error> {
+        self.read_endian(endian)
     }
 
+    /// Reads 128 bit signed integer in byte order selected at runtime.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
     #[inline]
-    fn read_le(reader: &mut Reader<'_>) -> Result<Self, Error> {
-        reader.read_byte().map_err(From::from)
+    fn read_i128(&mut self, endian: Endianness) -> Result<i128, Error> {
+        self.read_endian(endian)
     }
-}
 
-impl FromReader for u16 {
-    read_unsigned!(u16);
-}
-impl FromReader for u32 {
-    read_unsigned!(u32);
-}
-impl FromReader for u64 {
-    read_unsigned!(u64);
-}
-impl FromReader for u128 {
-    read_unsigned!(u128);
-}
+    /// Reads 16 bit signed integer in big endian and multiplies it by
+    /// `scale` to get the value in engineering units.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
+    #[inline]
+    fn read_scaled_i16be(&mut self, scale: f64) -> Result<f64, Error> {
+        Ok(f64::from(self.read_i16be()?) * scale)
+    }
 
-impl FromReader for i8 {
-    read_signed!(u8);
-}
-impl FromReader for i16 {
-    read_signed!(u16);
-}
-impl FromReader for i32 {
-    read_signed!(u32);
-}
-impl FromReader for i64 {
-    read_signed!(u64);
-}
-impl FromReader for i128 {
-    read_signed!(u128);
-}
+    /// Reads 16 bit signed integer in little endian and multiplies it by
+    /// `scale` to get the value in engineering units.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
+    #[inline]
+    fn read_scaled_i16le(&mut self, scale: f64) -> Result<f64, Error> {
+        Ok(f64::from(self.read_i16le()?) * scale)
+    }
 
-#[cfg(feature = "use_std")]
-impl FromReader for Ipv4Addr {
-    fn read_be(reader: &mut Reader<'_>) -> Result<Self, Error> {
-        reader.read_u32be().map(Ipv4Addr::from)
+    /// Reads 16 bit unsigned integer in big endian and multiplies it by
+    /// `scale` to get the value in engineering units.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
+    #[inline]
+    fn read_scaled_u16be(&mut self, scale: f64) -> Result<f64, Error> {
+        Ok(f64::from(self.read_u16be()?) * scale)
     }
 
-    fn read_le(reader: &mut Reader<'_>) -> Result<Self, Error> {
-        reader.read_u32le().map(Ipv4Addr::from)
+    /// Reads 16 bit unsigned integer in little endian and multiplies it by
+    /// `scale` to get the value in engineering units.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
+    #[inline]
+    fn read_scaled_u16le(&mut self, scale: f64) -> Result<f64, Error> {
+        Ok(f64::from(self.read_u16le()?) * scale)
     }
-}
 
-#[cfg(feature = "use_std")]
-impl FromReader for Ipv6Addr {
-    fn read_be(reader: &mut Reader<'_>) -> Result<Self, Error> {
-        reader.read_u128be().map(Ipv6Addr::from)
+    /// Reads 32 bit signed integer in big endian and multiplies it by
+    /// `scale` to get the value in engineering units.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
+    #[inline]
+    fn read_scaled_i32be(&mut self, scale: f64) -> Result<f64, Error> {
+        Ok(f64::from(self.read_i32be()?) * scale)
     }
 
-    fn read_le(reader: &mut Reader<'_>) -> Result<Self, Error> {
-        reader.read_u128le().map(Ipv6Addr::from)
+    /// Reads 32 bit signed integer in little endian and multiplies it by
+    /// `scale` to get the value in engineering units.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
+    #[inline]
+    fn read_scaled_i32le(&mut self, scale: f64) -> Result<f64, Error> {
+        Ok(f64::from(self.read_i32le()?) * scale)
     }
-}
+
+    /// Reads 32 bit unsigned integer in big endian and multiplies it by
+    /// `scale` to get the value in engineering units.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
+    #[inline]
+    fn read_scaled_u32be(&mut self, scale: f64) -> Result<f64, Error> {
+        Ok(f64::from(self.read_u32be()?) * scale)
+    }
+
+    /// Reads 32 bit unsigned integer in little endian and multiplies it by
+    /// `scale` to get the value in engineering units.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
+    #[inline]
+    fn read_scaled_u32le(&mut self, scale: f64) -> Result<f64, Error> {
+        Ok(f64::from(self.read_u32le()?) * scale)
+    }
+
+    /// Reads 16 bit signed PCM audio sample in little endian normalized to
+    /// `f32` in range [-1.0, 1.0).
+    ///
+    /// The sample is divided by 32768, so `i16::MIN` maps to exactly -1.0
+    /// and `i16::MAX` to slightly less than 1.0.
+    ///
+    /// Returns Ok(v) where v is the normalized sample, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    fn read_pcm_s16le_normalized(&mut self) -> Result<f32, Error> {
+        Ok(f32::from(self.read_i16le()?) / 32768.0)
+    }
+
+    /// Reads packed 24 bit signed PCM audio sample in little endian.
+    ///
+    /// This is the same as [ReaderExt::read_i24le]: the sample is sign
+    /// extended from bit 23.
+    ///
+    /// Returns Ok(v) where v is the sample read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
+    #[inline]
+    fn read_pcm_s24le(&mut self) -> Result<i32, Error> {
+        self.read_i24le()
+    }
+
+    /// Reads packed 24 bit signed PCM audio sample in little endian
+    /// normalized to `f32` in range [-1.0, 1.0).
+    ///
+    /// The sample is divided by 8388608 (2^23). A 24 bit sample fits in the
+    /// precision of `f32`, so the conversion is exact.
+    ///
+    /// Returns Ok(v) where v is the normalized sample, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    fn read_pcm_s24le_normalized(&mut self) -> Result<f32, Error> {
+        Ok(self.read_pcm_s24le()? as f32 / 8_388_608.0)
+    }
+
+    /// Reads `count` 16 bit signed PCM audio samples in little endian
+    /// normalized like [ReaderExt::read_pcm_s16le_normalized].
+    ///
+    /// Returns Ok(v) where v is a `Vec<f32>` of normalized samples, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn read_pcm_s16le_normalized_vec(&mut self, count: usize) -> Result<Vec<f32>, Error> {
+        let len = count.checked_mul(2).ok_or(Error::EndOfInput)?;
+        let buf = self.read_bytes_less_safe(len)?;
+        Ok(buf
+            .chunks_exact(2)
+            .map(|s| f32::from(i16::from_le_bytes([s[0], s[1]])) / 32768.0)
+            .collect())
+    }
+
+    /// Reads binary-coded decimal number with selectable nibble order.
+    ///
+    /// Each byte holds two decimal digits and bytes are read most significant
+    /// first. If `high_first` is true, the high nibble of each byte is the
+    /// more significant digit (`[0x12, 0x34]` reads as 1234), otherwise the
+    /// low nibble is (`[0x12, 0x34]` reads as 2143).
+    ///
+    /// Every nibble must be a digit. Filler nibble 0xF used by telephony BCD
+    /// to mark end of an odd-length number is not accepted.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading, or
+    /// Err(Error::InvalidValue) if a nibble is greater than 9, or
+    /// Err(Error::ParseError) if the number doesn't fit in `u64`.
+    #[inline]
+    fn read_bcd_ordered(&mut self, num_bytes: usize, high_first: bool) -> Result<u64, Error> {
+        let buf = self.read_bytes_less_safe(num_bytes)?;
+        let mut value = 0u64;
+        for &b in buf {
+            let (first, second) = if high_first {
+                (b >> 4, b & 0x0F)
+            } else {
+                (b & 0x0F, b >> 4)
+            };
+            for digit in [first, second] {
+                if digit > 9 {
+                    return Err(Error::InvalidValue);
+                }
+                value = value
+                    .checked_mul(10)
+                    .and_then(|v| v.checked_add(u64::from(digit)))
+                    .ok_or(Error::ParseError)?;
+            }
+        }
+        Ok(value)
+    }
+
+    /// Reads fixed-width ASCII octal number, like the numeric fields of tar
+    /// headers.
+    ///
+    /// Leading and trailing NUL and space padding is ignored, so for example
+    /// `b"0000644\0"` reads as 420.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading, or
+    /// Err(Error::ParseError) if the field has no digits, a byte is not an
+    /// octal digit or the number doesn't fit in `u64`.
+    #[inline]
+    fn read_ascii_octal(&mut self, num_bytes: usize) -> Result<u64, Error> {
+        let buf = self.read_bytes_less_safe(num_bytes)?;
+        parse_ascii_number(buf, 8)
+    }
+
+    /// Reads fixed-width ASCII decimal number.
+    ///
+    /// Leading and trailing NUL and space padding is ignored, so for example
+    /// `b"  42\0"` reads as 42.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading, or
+    /// Err(Error::ParseError) if the field has no digits, a byte is not a
+    /// decimal digit or the number doesn't fit in `u64`.
+    #[inline]
+    fn read_ascii_decimal(&mut self, num_bytes: usize) -> Result<u64, Error> {
+        let buf = self.read_bytes_less_safe(num_bytes)?;
+        parse_ascii_number(buf, 10)
+    }
+
+    /// Reads ASN.1 DER identifier and returns its tag number.
+    ///
+    /// Tag numbers up to 30 are stored in the low 5 bits of the identifier
+    /// byte. Larger tag numbers use the high-tag-number form: the low 5 bits
+    /// are all set and the tag number follows in groups of 7 bits, most
+    /// significant group first, the high bit of each byte set when more
+    /// bytes follow. The class and constructed bits of the identifier are
+    /// ignored.
+    ///
+    /// Returns Ok(v) where v is the tag number, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading, or
+    /// Err(Error::ParseError) if the high-tag-number form isn't minimal or
+    /// the tag number doesn't fit in `u32`.
+    #[inline]
+    fn read_der_tag(&mut self) -> Result<u32, Error> {
+        let number = self.read_u8()? & 0x1F;
+        if number != 0x1F {
+            return Ok(u32::from(number));
+        }
+        let mut byte = self.read_u8()?;
+        // Leading zero group isn't minimal.
+        if byte == 0x80 {
+            return Err(Error::ParseError);
+        }
+        let mut tag = 0u32;
+        loop {
+            if tag > u32::MAX >> 7 {
+                return Err(Error::ParseError);
+            }
+            tag = (tag << 7) | u32::from(byte & 0x7F);
+            if byte & 0x80 == 0 {
+                break;
+            }
+            byte = self.read_u8()?;
+        }
+        // Tag numbers up to 30 must use the short form.
+        if tag < 0x1F {
+            return Err(Error::ParseError);
+        }
+        Ok(tag)
+    }
+
+    /// Reads MIDI style variable-length quantity.
+    ///
+    /// The value is encoded in groups of 7 bits, most significant group
+    /// first. The high bit of each byte is set when more bytes follow. At most
+    /// four bytes (28 bits) are read.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading, or
+    /// Err(Error::ParseError) if the value doesn't fit in 28 bits.
+    #[inline]
+    fn read_vlq_u32(&mut self) -> Result<u32, Error> {
+        varint::read_vlq_u32(self).map(|(value, _)| value)
+    }
+
+    /// Reads signed MIDI style variable-length quantity.
+    ///
+    /// The groups are read like in [ReaderExt::read_vlq_u32] and the result
+    /// is a two's complement number of all the bits read. The sign bit is the
+    /// highest data bit (0x40) of the first, most significant, group. For
+    /// example `0x7F` reads as -1 and 64 must be encoded as `[0x80, 0x40]`.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading, or
+    /// Err(Error::ParseError) if the value doesn't fit in 28 bits.
+    #[inline]
+    fn read_vlq_i32(&mut self) -> Result<i32, Error> {
+        let (value, bits) = varint::read_vlq_u32(self)?;
+        let shift = 32 - bits;
+        Ok(((value << shift) as i32) >> shift)
+    }
+
+    /// Reads unsigned LEB128 encoded 64 bit integer, as used by DWARF and
+    /// WebAssembly.
+    ///
+    /// The value is encoded in groups of 7 bits, least significant group
+    /// first. The high bit of each byte is set when more bytes follow.
+    /// Overlong encodings with redundant trailing zero groups, like
+    /// `[0x80, 0x00]` for 0, are accepted as long as they fit in ten bytes.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading, or
+    /// Err(Error::ParseError) if the encoding is longer than ten bytes or the
+    /// value doesn't fit in `u64`.
+    #[inline]
+    fn read_uleb128(&mut self) -> Result<u64, Error> {
+        varint::read_uleb128_u64(self)
+    }
+
+    /// Reads unsigned LEB128 encoded 64 bit integer which must not be zero.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading, or
+    /// Err(Error::ParseError) if the value doesn't fit in `u64`, or
+    /// Err(Error::InvalidValue) if the value is zero.
+    #[inline]
+    fn read_uleb128_nonzero_u64(&mut self) -> Result<NonZeroU64, Error> {
+        NonZeroU64::new(self.read_uleb128()?).ok_or(Error::InvalidValue)
+    }
+
+    /// Reads a duration stored as unsigned LEB128 encoded microseconds.
+    ///
+    /// Returns Ok(v) where v is the `Duration` read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading, or Err(Error::ParseError) if the value doesn't fit in
+    /// `u64`.
+    #[inline]
+    #[cfg(feature = "use_std")]
+    fn read_uleb128_duration_micros(&mut self) -> Result<Duration, Error> {
+        self.read_uleb128().map(Duration::from_micros)
+    }
+
+    /// Reads a duration stored as 32 bit unsigned integer of seconds in big
+    /// endian.
+    ///
+    /// Returns Ok(v) where v is the `Duration` read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    #[cfg(feature = "use_std")]
+    fn read_duration_secs_u32be(&mut self) -> Result<Duration, Error> {
+        self.read_u32be()
+            .map(|secs| Duration::from_secs(u64::from(secs)))
+    }
+
+    /// Reads a duration stored as 64 bit unsigned integer of milliseconds in
+    /// big endian.
+    ///
+    /// Returns Ok(v) where v is the `Duration` read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    #[cfg(feature = "use_std")]
+    fn read_duration_millis_u64be(&mut self) -> Result<Duration, Error> {
+        self.read_u64be().map(Duration::from_millis)
+    }
+
+    /// Reads signed LEB128 encoded 64 bit integer.
+    ///
+    /// The groups are read like in [ReaderExt::read_uleb128] and the result
+    /// is sign-extended from the highest data bit (0x40) of the last group.
+    /// Overlong encodings with redundant trailing sign extension groups are
+    /// accepted as long as they fit in ten bytes.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading, or
+    /// Err(Error::ParseError) if the encoding is longer than ten bytes or the
+    /// value doesn't fit in `i64`.
+    #[inline]
+    fn read_sleb128(&mut self) -> Result<i64, Error> {
+        varint::read_sleb128_i64(self)
+    }
+
+    /// Reads unsigned LEB128 encoded integer of any size into a caller
+    /// provided accumulator.
+    ///
+    /// For each 7 bit group, least significant first, calls `accumulate`
+    /// with the shift of the group and its seven bits. The number of groups
+    /// is not limited, so `accumulate` should return an error when the value
+    /// grows too large for the caller's type.
+    ///
+    /// Returns Ok(()) after the last group, or Err(Error::EndOfInput) if the
+    /// Reader encountered an end of the input while reading, or the first
+    /// error returned by `accumulate`.
+    ///
+    /// ```
+    /// use untrusted::{Input, Reader};
+    /// use untrustended::{Error, ReaderExt};
+    ///
+    /// let buf = [0xE5, 0x8E, 0x26];
+    /// let mut reader = Reader::new(Input::from(&buf));
+    /// let mut value = 0u128;
+    /// reader
+    ///     .read_uleb128_generic(|shift, bits| {
+    ///         let bits = u128::from(bits).checked_shl(shift).ok_or(Error::ParseError)?;
+    ///         value |= bits;
+    ///         Ok(())
+    ///     })
+    ///     .unwrap();
+    /// assert_eq!(value, 624485);
+    /// ```
+    #[inline]
+    fn read_uleb128_generic<F>(&mut self, mut accumulate: F) -> Result<(), Error>
+    where
+        F: FnMut(u32, u8) -> Result<(), Error>,
+    {
+        let mut shift = 0u32;
+        loop {
+            let byte = self.read_u8()?;
+            accumulate(shift, byte & 0x7F)?;
+            if byte & 0x80 == 0 {
+                return Ok(());
+            }
+            shift = shift.checked_add(7).ok_or(Error::ParseError)?;
+        }
+    }
+
+    /// Reads signed LEB128 encoded 64 bit integer and the length of its
+    /// encoding.
+    ///
+    /// Returns Ok((v, n)) where v is the value read and n is the number of
+    /// bytes consumed, or Err(Error::EndOfInput) if the Reader encountered an
+    /// end of the input while reading, or Err(Error::ParseError) if the value
+    /// doesn't fit in `i64`.
+    #[inline]
+    fn read_sleb128_i64_counted(&mut self) -> Result<(i64, usize), Error>
+    where
+        Self: Cursor<'a>,
+    {
+        let (bytes, value) = self.read_partial(|input| varint::read_sleb128_i64(input))?;
+        Ok((value, bytes.len()))
+    }
+
+    /// Reads zigzag encoded 64 bit integer (Protocol Buffers `sint64`) and
+    /// the length of its encoding.
+    ///
+    /// Returns Ok((v, n)) where v is the value read and n is the number of
+    /// bytes consumed, or Err(Error::EndOfInput) if the Reader encountered an
+    /// end of the input while reading, or Err(Error::ParseError) if the value
+    /// doesn't fit in `i64`.
+    #[inline]
+    fn read_sint64_varint_counted(&mut self) -> Result<(i64, usize), Error>
+    where
+        Self: Cursor<'a>,
+    {
+        let (bytes, value) = self.read_partial(|input| varint::read_zigzag_i64(input))?;
+        Ok((value, bytes.len()))
+    }
+
+    /// Reads signed LEB128 encoded 16 bit integer.
+    ///
+    /// The value is decoded like a 64 bit integer and then checked to fit in
+    /// `i16`, so an out of range value is rejected instead of truncated.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading, or
+    /// Err(Error::ParseError) if the value doesn't fit in `i64`, or
+    /// Err(Error::InvalidValue) if the value doesn't fit in `i16`.
+    #[inline]
+    fn read_sleb128_i16(&mut self) -> Result<i16, Error> {
+        let value = varint::read_sleb128_i64(self)?;
+        i16::try_from(value).map_err(|_| Error::InvalidValue)
+    }
+
+    /// Reads signed 64 bit varint encoded as given by `mode`.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading, or
+    /// Err(Error::ParseError) if the value doesn't fit in `i64`.
+    #[inline]
+    fn read_signed_varint(&mut self, mode: SignedVarintMode) -> Result<i64, Error> {
+        match mode {
+            SignedVarintMode::Sleb128 => varint::read_sleb128_i64(self),
+            SignedVarintMode::Zigzag => varint::read_zigzag_i64(self),
+        }
+    }
+
+    /// Reads zigzag encoded 32 bit integer stored as fixed-width unsigned
+    /// integer in Big Endian byte order.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
+    #[inline]
+    fn read_zigzag_fixed32_be(&mut self) -> Result<i32, Error> {
+        let value: u32 = self.read_be()?;
+        Ok(varint::zigzag_decode_u64(u64::from(value)) as i32)
+    }
+
+    /// Reads zigzag encoded 64 bit integer stored as fixed-width unsigned
+    /// integer in Big Endian byte order.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
+    #[inline]
+    fn read_zigzag_fixed64_be(&mut self) -> Result<i64, Error> {
+        self.read_be().map(varint::zigzag_decode_u64)
+    }
+
+    /// Reads enum discriminant of given width and converts it into an enum.
+    ///
+    /// Reads an unsigned integer of `width_bytes` bytes (from 1 to 8) in
+    /// given byte order and converts it with `E::try_from`.
+    ///
+    /// Returns Ok(v) where v is the enum value, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading, or
+    /// Err(Error::InvalidValue) if the conversion failed, or
+    /// Err(Error::ParseError) if `width_bytes` is not from 1 to 8.
+    #[inline]
+    fn read_enum_var<E: TryFrom<u64>>(
+        &mut self,
+        width_bytes: usize,
+        endian: Endianness,
+    ) -> Result<E, Error> {
+        if !(1..=8).contains(&width_bytes) {
+            return Err(Error::ParseError);
+        }
+        let buf = self.read_bytes_less_safe(width_bytes)?;
+        let fold = |acc: u64, b: &u8| (acc << 8) | u64::from(*b);
+        let value = match endian {
+            Endianness::Big => buf.iter().fold(0, fold),
+            Endianness::Little => buf.iter().rev().fold(0, fold),
+        };
+        E::try_from(value).map_err(|_| Error::InvalidValue)
+    }
+
+    /// Reads one byte discriminant and maps it into an enum with `f`.
+    ///
+    /// `f` returns `None` for unknown discriminants, for example
+    /// `reader.read_enum_u8(Opcode::from_u8)`.
+    ///
+    /// Returns Ok(v) where v is the enum value, or Err(Error::EndOfInput) if
+    /// the Reader is at the end of the input, or Err(Error::InvalidValue) if
+    /// `f` returned `None`.
+    #[inline]
+    fn read_enum_u8<T, F>(&mut self, f: F) -> Result<T, Error>
+    where
+        F: FnOnce(u8) -> Option<T>,
+    {
+        f(self.read_u8()?).ok_or(Error::InvalidValue)
+    }
+
+    /// Reads 16 bit discriminant in Big Endian byte order and maps it into an
+    /// enum with `f`.
+    ///
+    /// Returns Ok(v) where v is the enum value, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading, or
+    /// Err(Error::InvalidValue) if `f` returned `None`.
+    #[inline]
+    fn read_enum_u16be<T, F>(&mut self, f: F) -> Result<T, Error>
+    where
+        F: FnOnce(u16) -> Option<T>,
+    {
+        f(self.read_u16be()?).ok_or(Error::InvalidValue)
+    }
+
+    /// Reads an optional value which is present when `cond` is true, for
+    /// example when a flag read earlier is set.
+    ///
+    /// Calls `f` only if `cond` is true. Otherwise nothing is consumed.
+    ///
+    /// Returns Ok(Some(v)) where v is the value returned by `f`, or Ok(None)
+    /// if `cond` is false, or the error returned by `f`.
+    #[inline]
+    fn read_if<T, F>(&mut self, cond: bool, f: F) -> Result<Option<T>, Error>
+    where
+        F: FnOnce(&mut Self) -> Result<T, Error>,
+    {
+        if cond {
+            f(self).map(Some)
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Reads a compact one byte value or, if the byte is a marker, an
+    /// extended value.
+    ///
+    /// Reads one byte. Unless it equals `extend_marker`, it is the value.
+    /// Otherwise `f` is called to read the extended value following the
+    /// marker.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading, or the error
+    /// returned by `f`.
+    #[inline]
+    fn read_small_or_extended<F>(&mut self, extend_marker: u8, f: F) -> Result<u64, Error>
+    where
+        F: FnOnce(&mut Self) -> Result<u64, Error>,
+    {
+        match self.read_u8()? {
+            b if b == extend_marker => f(self),
+            b => Ok(u64::from(b)),
+        }
+    }
+
+    /// Reads MS-DOS packed date and time as used by ZIP and FAT.
+    ///
+    /// Reads little endian time word followed by little endian date word, in
+    /// the order they are stored in ZIP headers and FAT directory entries.
+    /// Year counts from 1980 and seconds are stored in two second units. Day
+    /// is not checked against the length of the month.
+    ///
+    /// Returns Ok((year, month, day, hour, minute, second)), or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading, or Err(Error::InvalidValue) if a field is out of range.
+    #[inline]
+    fn read_dos_datetime(&mut self) -> Result<(u16, u8, u8, u8, u8, u8), Error> {
+        let time = self.read_u16le()?;
+        let date = self.read_u16le()?;
+        let hour = (time >> 11) as u8;
+        let minute = ((time >> 5) & 0x3F) as u8;
+        let second = ((time & 0x1F) as u8) * 2;
+        let year = 1980 + (date >> 9);
+        let month = ((date >> 5) & 0x0F) as u8;
+        let day = (date & 0x1F) as u8;
+        if hour > 23 || minute > 59 || second > 58 || !(1..=12).contains(&month) || day == 0 {
+            return Err(Error::InvalidValue);
+        }
+        Ok((year, month, day, hour, minute, second))
+    }
+
+    /// Reads ISO 8601 UTC timestamp stored as 20 ASCII characters, like
+    /// `2024-03-14T12:00:00Z`.
+    ///
+    /// The day is checked against the length of the month, including leap
+    /// years. Second 60 is accepted for leap seconds.
+    ///
+    /// Returns Ok((year, month, day, hour, minute, second)), or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading, or Err(Error::ParseError) if the timestamp is not in
+    /// the format above, or Err(Error::InvalidValue) if a field is out of
+    /// range.
+    #[inline]
+    fn read_iso8601_ascii(&mut self) -> Result<(u16, u8, u8, u8, u8, u8), Error> {
+        // Separators must match exactly, digits are checked when parsed.
+        const TEMPLATE: &[u8] = b"0000-00-00T00:00:00Z";
+        let buf = self.read_bytes_less_safe(TEMPLATE.len())?;
+        if buf.iter().zip(TEMPLATE).any(|(&b, &t)| t != b'0' && b != t) {
+            return Err(Error::ParseError);
+        }
+        let number = |start: usize, len: usize| {
+            buf[start..start + len]
+                .iter()
+                .try_fold(0u16, |value, &b| match b {
+                    b'0'..=b'9' => Ok(value * 10 + u16::from(b - b'0')),
+                    _ => Err(Error::ParseError),
+                })
+        };
+        let year = number(0, 4)?;
+        // Two digit fields are at most 99.
+        let [month, day, hour, minute, second] =
+            [5, 8, 11, 14, 17].map(|start| number(start, 2).map(|v| v as u8));
+        let (month, day, hour, minute, second) = (month?, day?, hour?, minute?, second?);
+        let leap_year = year % 4 == 0 && (year % 100 != 0 || year % 400 == 0);
+        let days_in_month = match month {
+            2 if leap_year => 29,
+            2 => 28,
+            4 | 6 | 9 | 11 => 30,
+            _ => 31,
+        };
+        if !(1..=12).contains(&month)
+            || !(1..=days_in_month).contains(&day)
+            || hour > 23
+            || minute > 59
+            || second > 60
+        {
+            return Err(Error::InvalidValue);
+        }
+        Ok((year, month, day, hour, minute, second))
+    }
+
+    /// Reads given amount of bytes.
+    ///
+    /// Access the given amount of bytes as a slice so it can be processed by
+    /// functions that are not written using the untrusted's Input/Reader
+    /// framework.
+    ///
+    /// Returns Ok(v) where v is a `&[u8]` of bytes read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    fn read_bytes_less_safe(&mut self, num_bytes: usize) -> Result<&'a [u8], Error> {
+        Ok(self.read_bytes(num_bytes).map(|v| v.as_slice_less_safe())?)
+    }
+
+    /// Reads `total_len` bytes split into a header of `header_len` bytes and
+    /// the rest.
+    ///
+    /// Returns Ok((header, body)) where header is a `&[u8]` of the first
+    /// `header_len` bytes and body of the remaining bytes, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading, or Err(Error::InvalidLength) if `header_len` is greater
+    /// than `total_len`.
+    #[inline]
+    fn read_split_at(
+        &mut self,
+        header_len: usize,
+        total_len: usize,
+    ) -> Result<(&'a [u8], &'a [u8]), Error> {
+        if header_len > total_len {
+            return Err(Error::InvalidLength);
+        }
+        let buf = self.read_bytes_less_safe(total_len)?;
+        Ok(buf.split_at(header_len))
+    }
+
+    /// Reads bytes prefixed with their length as 8 bit unsigned integer.
+    ///
+    /// Returns Ok(v) where v is a `&[u8]` of bytes read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading the length or the bytes. [TrackingReader] returns
+    /// Err(Error::LengthExceedsInput { needed, available }) instead if the
+    /// length exceeds the remaining input.
+    #[inline]
+    fn read_length_prefixed_u8(&mut self) -> Result<&'a [u8], Error> {
+        let len = self.read_u8()?;
+        self.read_bytes_less_safe(usize::from(len))
+    }
+
+    /// Reads bytes prefixed with their length as 16 bit unsigned integer in
+    /// Big Endian byte order.
+    ///
+    /// Returns Ok(v) where v is a `&[u8]` of bytes read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading the length or the bytes. [TrackingReader] returns
+    /// Err(Error::LengthExceedsInput { needed, available }) instead if the
+    /// length exceeds the remaining input.
+    #[inline]
+    fn read_length_prefixed_u16be(&mut self) -> Result<&'a [u8], Error> {
+        let len = self.read_u16be()?;
+        self.read_bytes_less_safe(usize::from(len))
+    }
+
+    /// Reads bytes prefixed with their length as 32 bit unsigned integer in
+    /// Big Endian byte order.
+    ///
+    /// Returns Ok(v) where v is a `&[u8]` of bytes read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading the length or the bytes. [TrackingReader] returns
+    /// Err(Error::LengthExceedsInput { needed, available }) instead if the
+    /// length exceeds the remaining input.
+    #[inline]
+    fn read_length_prefixed_u32be(&mut self) -> Result<&'a [u8], Error> {
+        let len = self.read_u32be()?;
+        let len = usize::try_from(len).map_err(|_| Error::EndOfInput)?;
+        self.read_bytes_less_safe(len)
+    }
+
+    /// Reads a frame of a sync word, a length and a payload.
+    ///
+    /// The frame must start with `sync`. It is followed by the length of the
+    /// payload as an unsigned integer of `len_bytes` bytes (from 1 to 8) in
+    /// given byte order, and the payload.
+    ///
+    /// Returns Ok(v) where v is a `&[u8]` of the payload, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading the sync word or the length, or Err(Error::InvalidValue)
+    /// if the sync word doesn't match, or Err(Error::InvalidLength) if the
+    /// length exceeds the remaining input, or Err(Error::ParseError) if
+    /// `len_bytes` is not from 1 to 8.
+    #[inline]
+    fn read_framed(
+        &mut self,
+        sync: &[u8],
+        len_bytes: usize,
+        endian: Endianness,
+    ) -> Result<&'a [u8], Error> {
+        if !(1..=8).contains(&len_bytes) {
+            return Err(Error::ParseError);
+        }
+        if self.read_bytes_less_safe(sync.len())? != sync {
+            return Err(Error::InvalidValue);
+        }
+        let len: u64 = self.read_enum_var(len_bytes, endian)?;
+        let len = usize::try_from(len).map_err(|_| Error::InvalidLength)?;
+        self.read_bytes_less_safe(len).map_err(|e| match e {
+            Error::EndOfInput => Error::InvalidLength,
+            e => e,
+        })
+    }
+
+    /// Skips given amount of bytes, like reserved fields or padding.
+    ///
+    /// Nothing is skipped if fewer than `num_bytes` bytes remain.
+    ///
+    /// Returns Ok(()) if the bytes were skipped, or Err(Error::EndOfInput)
+    /// if the Reader encountered an end of the input while reading.
+    #[inline]
+    fn skip_bytes(&mut self, num_bytes: usize) -> Result<(), Error> {
+        self.read_bytes(num_bytes)?;
+        Ok(())
+    }
+
+    /// Reads all remaining bytes.
+    ///
+    /// This suits a last field which extends to the end of the input. When
+    /// the reader is already at the end, an empty slice is returned.
+    ///
+    /// Returns Ok(v) where v is a `&[u8]` of bytes read.
+    #[inline]
+    fn read_remaining(&mut self) -> Result<&'a [u8], Error>
+    where
+        Self: Cursor<'a>,
+    {
+        self.read_with(|input| Ok(input.read_bytes_to_end().as_slice_less_safe()))
+    }
+
+    /// Reads all remaining bytes as records of `record_size` bytes.
+    ///
+    /// This suits a trailing array of fixed-size records without a count.
+    ///
+    /// Returns Ok(v) where v is a `Vec<&[u8]>` of records read, or
+    /// Err(Error::InvalidLength) if the remaining length isn't a multiple of
+    /// `record_size`, or Err(Error::ParseError) if `record_size` is zero.
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn read_remaining_chunks(&mut self, record_size: usize) -> Result<Vec<&'a [u8]>, Error>
+    where
+        Self: Cursor<'a>,
+    {
+        if record_size == 0 {
+            return Err(Error::ParseError);
+        }
+        let buf = self.read_remaining()?;
+        if buf.len() % record_size != 0 {
+            return Err(Error::InvalidLength);
+        }
+        Ok(buf.chunks(record_size).collect())
+    }
+
+    /// Reads given amount of bytes, or all remaining bytes if there are
+    /// fewer.
+    ///
+    /// Unlike [ReaderExt::read_bytes_less_safe] this is lenient: running out
+    /// of input is not an error, so the returned slice can be shorter than
+    /// `num_bytes`, even empty. This suits formats where the last chunk may
+    /// be truncated. Callers that need exactly `num_bytes` must check the
+    /// length.
+    ///
+    /// Returns Ok(v) where v is a `&[u8]` of at most `num_bytes` bytes read.
+    #[inline]
+    fn read_exact_or_rest(&mut self, num_bytes: usize) -> Result<&'a [u8], Error>
+    where
+        Self: Cursor<'a>,
+    {
+        self.read_with(|input| {
+            let bytes = match input.read_bytes(num_bytes) {
+                Ok(bytes) => bytes,
+                Err(EndOfInput) => input.read_bytes_to_end(),
+            };
+            Ok(bytes.as_slice_less_safe())
+        })
+    }
+
+    /// Reads CRC-16/CCITT checksum in big endian and verifies it against
+    /// `data`.
+    ///
+    /// The checksum is calculated with [crc::crc16_ccitt].
+    ///
+    /// Returns Ok(()) if the checksum matched, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading, or
+    /// Err(Error::InvalidValue) if the checksum didn't match.
+    #[inline]
+    fn read_crc16_ccitt_verify(&mut self, data: &[u8]) -> Result<(), Error> {
+        if self.read_u16be()? != crc::crc16_ccitt(data) {
+            return Err(Error::InvalidValue);
+        }
+        Ok(())
+    }
+
+    /// Reads bytes matching given magic signature or tag.
+    ///
+    /// Reads `expected.len()` bytes and compares them to `expected`, for
+    /// example `reader.expect_bytes(b"\x89PNG")?` at the start of a parser.
+    ///
+    /// Returns Ok(()) if the bytes matched, or Err(Error::EndOfInput) if the
+    /// Reader encountered an end of the input while reading, or
+    /// Err(Error::InvalidValue) if the bytes didn't match.
+    #[inline]
+    fn expect_bytes(&mut self, expected: &[u8]) -> Result<(), Error> {
+        if self.read_bytes_less_safe(expected.len())? != expected {
+            return Err(Error::InvalidValue);
+        }
+        Ok(())
+    }
+
+    /// Reads bytes matching given ASCII keyword ignoring case.
+    ///
+    /// Reads `expected.len()` bytes and compares them to `expected` so that
+    /// ASCII letters match regardless of case, for example `b"HTTP"` matches
+    /// `http`. Other bytes must match exactly.
+    ///
+    /// Returns Ok(()) if the bytes matched, or Err(Error::EndOfInput) if the
+    /// Reader encountered an end of the input while reading, or
+    /// Err(Error::InvalidValue) if the bytes didn't match.
+    #[inline]
+    fn read_matching_ascii_ci(&mut self, expected: &[u8]) -> Result<(), Error> {
+        let buf = self.read_bytes_less_safe(expected.len())?;
+        if !buf.eq_ignore_ascii_case(expected) {
+            return Err(Error::InvalidValue);
+        }
+        Ok(())
+    }
+
+    /// Reads given amount of bytes in chunks.
+    ///
+    /// Reads `total` bytes and calls `f` for each consecutive slice of at most
+    /// `chunk` bytes. Every slice except possibly the last one is exactly
+    /// `chunk` bytes long. This allows processing large payloads
+    /// incrementally (for example hashing) without copying them first.
+    ///
+    /// The availability of `total` bytes is checked before `f` is called, so
+    /// `f` is never called for a truncated payload.
+    ///
+    /// Returns Ok(()) if all chunks were processed, or Err(Error::EndOfInput)
+    /// if the Reader encountered an end of the input while reading, or
+    /// Err(Error::ParseError) if `chunk` is zero, or the first error returned
+    /// by `f`.
+    #[inline]
+    fn read_bytes_chunked<F>(&mut self, total: usize, chunk: usize, mut f: F) -> Result<(), Error>
+    where
+        F: FnMut(&[u8]) -> Result<(), Error>,
+    {
+        if chunk == 0 {
+            return Err(Error::ParseError);
+        }
+        let buf = self.read_bytes_less_safe(total)?;
+        buf.chunks(chunk).try_for_each(&mut f)
+    }
+
+    /// Reads bytes until given delimiter.
+    ///
+    /// The delimiter is consumed but not included in the returned bytes.
+    ///
+    /// Returns Ok(v) where v is a `&[u8]` of bytes read before the delimiter,
+    /// or Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// before finding the delimiter.
+    #[inline]
+    fn read_bytes_until(&mut self, delimiter: u8) -> Result<&'a [u8], Error>
+    where
+        Self: Cursor<'a>,
+    {
+        let (bytes, ()) = self.read_partial(|input| {
+            while input.read_u8()? != delimiter {}
+            Ok(())
+        })?;
+        let bytes = bytes.as_slice_less_safe();
+        // Delimiter was read, so there's always at least one byte.
+        Ok(&bytes[..bytes.len() - 1])
+    }
+
+    /// Reads bytes until given multi-byte pattern, like `b"\r\n\r\n"` ending
+    /// HTTP headers.
+    ///
+    /// The pattern is consumed but not included in the returned bytes.
+    ///
+    /// Returns Ok(v) where v is a `&[u8]` of bytes read before the first
+    /// occurrence of the pattern, or Err(Error::EndOfInput) if the Reader
+    /// encountered an end of the input before finding the pattern, or
+    /// Err(Error::ParseError) if the pattern is empty.
+    #[inline]
+    fn read_bytes_until_pattern(&mut self, pattern: &[u8]) -> Result<&'a [u8], Error>
+    where
+        Self: Cursor<'a>,
+    {
+        if pattern.is_empty() {
+            return Err(Error::ParseError);
+        }
+        let (bytes, ()) = self.read_partial(|input| {
+            // Length of the longest prefix of the pattern which is a suffix
+            // of the bytes read so far.
+            let mut matched = 0;
+            while matched < pattern.len() {
+                let b = input.read_u8()?;
+                matched = (1..=matched + 1)
+                    .rev()
+                    .find(|&len| {
+                        pattern[len - 1] == b
+                            && pattern[..len - 1] == pattern[matched + 1 - len..matched]
+                    })
+                    .unwrap_or(0);
+            }
+            Ok(())
+        })?;
+        let bytes = bytes.as_slice_less_safe();
+        // Pattern was read, so it's always at the end of the bytes.
+        Ok(&bytes[..bytes.len() - pattern.len()])
+    }
+
+    /// Reads `N` bytes into an array.
+    ///
+    /// This is handy for fixed-size values like hashes and nonces, and
+    /// doesn't allocate.
+    ///
+    /// Returns Ok(v) where v is a `[u8; N]` of bytes read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    fn read_array<const N: usize>(&mut self) -> Result<[u8; N], Error> {
+        let buf = self.read_bytes_less_safe(N)?;
+        let mut arr = [0u8; N];
+        arr.copy_from_slice(buf);
+        Ok(arr)
+    }
+
+    /// Reads `N` bytes as a reference to an array.
+    ///
+    /// Like [ReaderExt::read_array] but borrows the bytes from the input
+    /// instead of copying them.
+    ///
+    /// Returns Ok(v) where v is a `&[u8; N]` of bytes read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    fn read_bytes_array<const N: usize>(&mut self) -> Result<&'a [u8; N], Error> {
+        let buf = self.read_bytes_less_safe(N)?;
+        buf.try_into().map_err(|_| Error::EndOfInput)
+    }
+
+    /// Reads an array of 8 bit signed integers.
+    ///
+    /// Reads `N` bytes and reinterprets each of them as `i8`. This is useful
+    /// for example with signed 8 bit audio samples.
+    ///
+    /// Returns Ok(v) where v is a `[i8; N]` of values read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    fn read_i8_array<const N: usize>(&mut self) -> Result<[i8; N], Error> {
+        let buf = self.read_bytes_less_safe(N)?;
+        let mut arr = [0i8; N];
+        for (dst, src) in arr.iter_mut().zip(buf) {
+            *dst = *src as i8;
+        }
+        Ok(arr)
+    }
+
+    /// Reads an array of 64 bit unsigned integers in Big Endian byte order.
+    ///
+    /// Reads `N * 8` bytes at once, which is handy for example with the
+    /// state words of SHA-512. Nothing is consumed if fewer bytes remain.
+    ///
+    /// Returns Ok(v) where v is a `[u64; N]` of values read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    fn read_u64be_array<const N: usize>(&mut self) -> Result<[u64; N], Error> {
+        let len = N.checked_mul(8).ok_or(Error::EndOfInput)?;
+        let buf = self.read_bytes_less_safe(len)?;
+        let mut arr = [0u64; N];
+        for (dst, src) in arr.iter_mut().zip(buf.chunks_exact(8)) {
+            *dst = u64::from_be_bytes(src.try_into().expect("chunk of 8 bytes"));
+        }
+        Ok(arr)
+    }
+
+    /// Reads an array of 64 bit unsigned integers in Little Endian byte
+    /// order.
+    ///
+    /// Reads `N * 8` bytes at once. Nothing is consumed if fewer bytes
+    /// remain.
+    ///
+    /// Returns Ok(v) where v is a `[u64; N]` of values read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    fn read_u64le_array<const N: usize>(&mut self) -> Result<[u64; N], Error> {
+        let len = N.checked_mul(8).ok_or(Error::EndOfInput)?;
+        let buf = self.read_bytes_less_safe(len)?;
+        let mut arr = [0u64; N];
+        for (dst, src) in arr.iter_mut().zip(buf.chunks_exact(8)) {
+            *dst = u64::from_le_bytes(src.try_into().expect("chunk of 8 bytes"));
+        }
+        Ok(arr)
+    }
+
+    /// Reads `N` bytes into an array and converts them with `f`.
+    ///
+    /// This is an escape hatch for one-off custom decodings which the other
+    /// methods don't cover.
+    ///
+    /// Returns Ok(v) where v is the value returned by `f`, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading, or the error returned by `f`.
+    ///
+    /// ```
+    /// use untrusted::{Input, Reader};
+    /// use untrustended::ReaderExt;
+    ///
+    /// let buf = [0x12, 0x34, 0x56];
+    /// let mut reader = Reader::new(Input::from(&buf));
+    /// let value = reader.read_map_bytes(|[a, b, c]: [u8; 3]| {
+    ///     Ok(u32::from_le_bytes([a, b, c, 0]))
+    /// });
+    /// assert_eq!(value, Ok(0x563412));
+    /// ```
+    #[inline]
+    fn read_map_bytes<T, const N: usize, F>(&mut self, f: F) -> Result<T, Error>
+    where
+        F: FnOnce([u8; N]) -> Result<T, Error>,
+    {
+        let buf = self.read_bytes_less_safe(N)?;
+        let mut arr = [0u8; N];
+        arr.copy_from_slice(buf);
+        f(arr)
+    }
+
+    /// Reads one byte of flags.
+    ///
+    /// Returns Ok(v) where v is a [FlagByte] to query the flags from, or
+    /// Err(Error::EndOfInput) if the Reader is at the end of the input.
+    #[inline]
+    fn read_flag_byte(&mut self) -> Result<FlagByte, Error> {
+        self.read_u8().map(FlagByte)
+    }
+
+    /// Reads RGB color stored as three bytes.
+    ///
+    /// Returns Ok(v) where v is a `[u8; 3]` of red, green and blue, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    fn read_rgb(&mut self) -> Result<[u8; 3], Error> {
+        let buf = self.read_bytes_less_safe(3)?;
+        Ok([buf[0], buf[1], buf[2]])
+    }
+
+    /// Reads RGBA color stored as four bytes.
+    ///
+    /// Returns Ok(v) where v is a `[u8; 4]` of red, green, blue and alpha,
+    /// or Err(Error::EndOfInput) if the Reader encountered an end of the
+    /// input while reading.
+    #[inline]
+    fn read_rgba(&mut self) -> Result<[u8; 4], Error> {
+        let buf = self.read_bytes_less_safe(4)?;
+        Ok([buf[0], buf[1], buf[2], buf[3]])
+    }
+
+    /// Reads RGB565 packed color in big endian.
+    ///
+    /// The 5 bit red, 6 bit green and 5 bit blue components are scaled to 8
+    /// bits so that the maximum component value becomes 255.
+    ///
+    /// Returns Ok(v) where v is a `[u8; 3]` of red, green and blue, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    fn read_rgb565be(&mut self) -> Result<[u8; 3], Error> {
+        self.read_u16be().map(rgb565_to_rgb)
+    }
+
+    /// Reads RGB565 packed color in little endian.
+    ///
+    /// The 5 bit red, 6 bit green and 5 bit blue components are scaled to 8
+    /// bits so that the maximum component value becomes 255.
+    ///
+    /// Returns Ok(v) where v is a `[u8; 3]` of red, green and blue, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    fn read_rgb565le(&mut self) -> Result<[u8; 3], Error> {
+        self.read_u16le().map(rgb565_to_rgb)
+    }
+
+    /// Reads a point of two 32 bit floats in big endian, x first.
+    ///
+    /// Returns Ok((x, y)) where x and y are the coordinates read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    fn read_point_f32be(&mut self) -> Result<(f32, f32), Error> {
+        Ok((self.read_be()?, self.read_be()?))
+    }
+
+    /// Reads a point of two 32 bit floats in little endian, x first.
+    ///
+    /// Returns Ok((x, y)) where x and y are the coordinates read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    fn read_point_f32le(&mut self) -> Result<(f32, f32), Error> {
+        Ok((self.read_le()?, self.read_le()?))
+    }
+
+    /// Reads a point of two 64 bit floats in big endian, x first.
+    ///
+    /// Returns Ok((x, y)) where x and y are the coordinates read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    fn read_point_f64be(&mut self) -> Result<(f64, f64), Error> {
+        Ok((self.read_be()?, self.read_be()?))
+    }
+
+    /// Reads a point of two 64 bit floats in little endian, x first.
+    ///
+    /// Returns Ok((x, y)) where x and y are the coordinates read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    fn read_point_f64le(&mut self) -> Result<(f64, f64), Error> {
+        Ok((self.read_le()?, self.read_le()?))
+    }
+
+    /// Reads latitude and longitude stored as degrees multiplied by 10^7.
+    ///
+    /// Reads two 32 bit signed integers in big endian, latitude first, and
+    /// scales them to degrees.
+    ///
+    /// Returns Ok((lat, lon)) where lat and lon are in degrees, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading, or Err(Error::InvalidValue) if latitude is not within
+    /// [-90, 90] or longitude is not within [-180, 180].
+    #[inline]
+    fn read_geo_e7_be(&mut self) -> Result<(f64, f64), Error> {
+        const SCALE: f64 = 1e7;
+        let lat = f64::from(self.read_i32be()?) / SCALE;
+        let lon = f64::from(self.read_i32be()?) / SCALE;
+        if !(-90.0..=90.0).contains(&lat) || !(-180.0..=180.0).contains(&lon) {
+            return Err(Error::InvalidValue);
+        }
+        Ok((lat, lon))
+    }
+
+    /// Reads signed 16.16 fixed point number in big endian as `f32`.
+    ///
+    /// `f32` has 24 bits of precision, so fixed point values with more
+    /// significant bits are rounded as given by `mode`.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
+    #[inline]
+    fn read_fixed_16_16_rounded(&mut self, mode: RoundingMode) -> Result<f32, Error> {
+        const SCALE: f32 = 65536.0;
+        let raw = self.read_i32be()?;
+        let rounded = match mode {
+            RoundingMode::Nearest => raw as f32,
+            RoundingMode::Truncate => {
+                // Clear the bits below the 24 most significant bits, so the
+                // conversion is exact.
+                let magnitude = raw.unsigned_abs();
+                let excess = (32 - magnitude.leading_zeros()).saturating_sub(f32::MANTISSA_DIGITS);
+                let truncated = ((magnitude >> excess) << excess) as f32;
+                if raw < 0 {
+                    -truncated
+                } else {
+                    truncated
+                }
+            }
+        };
+        // Dividing by a power of two is exact.
+        Ok(rounded / SCALE)
+    }
+
+    /// Reads an array of [Readable] data structures.
+    ///
+    /// Calls `T::read` `N` times, stopping at the first error.
+    ///
+    /// Returns Ok(v) where v is a `[T::Output; N]` of values read, or the
+    /// first error returned by `T::read`.
+    #[inline]
+    fn read_readable_array<T: Readable, const N: usize>(&mut self) -> Result<[T::Output; N], Error>
+    where
+        Self: Cursor<'a>,
+    {
+        self.read_with(|input| {
+            let mut error = None;
+            let items: [Option<T::Output>; N] = core::array::from_fn(|_| {
+                if error.is_some() {
+                    return None;
+                }
+                T::read(input).map_err(|e| error = Some(e)).ok()
+            });
+            match error {
+                Some(e) => Err(e),
+                // Without an error every item was read.
+                None => Ok(items.map(|item| item.expect("item was read"))),
+            }
+        })
+    }
+
+    /// Reads a list of `count` [Readable] data structures.
+    ///
+    /// Calls `T::read` `count` times, stopping at the first error. The list
+    /// grows as the items are read instead of allocating room for `count`
+    /// items up front, so an untrusted `count` can't cause a large
+    /// allocation.
+    ///
+    /// Returns Ok(v) where v is a `Vec<T::Output>` of values read, or the
+    /// first error returned by `T::read`.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn read_vec<T: Readable>(&mut self, count: usize) -> Result<Vec<T::Output>, Error>
+    where
+        Self: Cursor<'a>,
+    {
+        self.read_with(|input| {
+            let mut items = Vec::new();
+            for _ in 0..count {
+                items.push(T::read(input)?);
+            }
+            Ok(items)
+        })
+    }
+
+    /// Reads a [Readable] data structure prefixed with its length in bytes
+    /// as unsigned LEB128.
+    ///
+    /// `T::read` is given only the bytes of the record, so it can't read past
+    /// the declared length, and it must consume all of them.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading the length
+    /// or the record, or Err(Error::ParseError) if the length is malformed
+    /// or `T::read` didn't consume exactly the declared length, or the first
+    /// error returned by `T::read`.
+    #[inline]
+    fn read_varint_prefixed_readable<T: Readable>(&mut self) -> Result<T::Output, Error> {
+        let len = varint::read_uleb128_u64(self)?;
+        let len = usize::try_from(len).map_err(|_| Error::ParseError)?;
+        self.read_bytes(len)?.read_all(Error::ParseError, T::read)
+    }
+
+    /// Reads a versioned structure.
+    ///
+    /// Calls `version_reader` to read the version, and then `body` with the
+    /// version to read the rest. This models structures where later versions
+    /// append fields: `body` reads the version-gated fields only when the
+    /// version has them.
+    ///
+    /// Returns Ok(v) where v is the value returned by `body`, or the first
+    /// error returned by `version_reader` or `body`.
+    ///
+    /// ```
+    /// use untrusted::{Input, Reader};
+    /// use untrustended::{Error, ReaderExt};
+    ///
+    /// let buf = [2, 0x00, 0x2A, 0x07];
+    /// let mut reader = Reader::new(Input::from(&buf));
+    /// let (id, flags) = reader
+    ///     .read_versioned(
+    ///         |input| input.read_u8().map(u32::from),
+    ///         |version, input| {
+    ///             let id = input.read_u16be()?;
+    ///             let flags = if version >= 2 { input.read_u8()? } else { 0 };
+    ///             Ok((id, flags))
+    ///         },
+    ///     )
+    ///     .unwrap();
+    /// assert_eq!((id, flags), (42, 7));
+    /// ```
+    #[inline]
+    fn read_versioned<T, V, B>(&mut self, version_reader: V, body: B) -> Result<T, Error>
+    where
+        V: FnOnce(&mut Reader<'a>) -> Result<u32, Error>,
+        B: FnOnce(u32, &mut Reader<'a>) -> Result<T, Error>,
+        Self: Cursor<'a>,
+    {
+        self.read_with(|input| {
+            let version = version_reader(input)?;
+            body(version, input)
+        })
+    }
+
+    /// Reads given amount of bytes as `Cow`.
+    ///
+    /// The bytes are always borrowed from the input. Returning `Cow` lets
+    /// APIs which sometimes need owned bytes handle both cases uniformly and
+    /// allocate with `into_owned()` only when needed.
+    ///
+    /// Returns Ok(v) where v is a `Cow::Borrowed` of bytes read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn read_bytes_cow(&mut self, num_bytes: usize) -> Result<Cow<'a, [u8]>, Error> {
+        self.read_bytes_less_safe(num_bytes).map(Cow::Borrowed)
+    }
+
+    /// Reads bytes as UTF-8 String.
+    ///
+    /// Length required is the amount of bytes to read, not the amount of UTF-8
+    /// characters.
+    ///
+    /// Read bytes are validated to be valid UTF-8 by
+    /// [`str::from_utf8`](https://doc.rust-lang.org/core/str/fn.from_utf8.html)
+    /// method.
+    ///
+    /// Returns Ok(v) where v is a `&str` of bytes read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading, or Err(Error::ParseError) if UTF-8 parsing failed.
+    #[inline]
+    fn read_utf8(&mut self, num_bytes: usize) -> Result<&'a str, Error> {
+        let buf = self.read_bytes_less_safe(num_bytes)?;
+        core::str::from_utf8(buf).map_err(From::from)
+    }
+
+    /// Reads bytes as printable UTF-8 String.
+    ///
+    /// Works like [ReaderExt::read_utf8], but additionally rejects control
+    /// characters (C0, DEL and C1) except the ones listed in `allowed`, for
+    /// example `&['\t']`.
+    ///
+    /// Returns Ok(v) where v is a `&str` of bytes read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading, or Err(Error::ParseError) if UTF-8 parsing failed, or
+    /// Err(Error::InvalidValue) if the string contains a control character
+    /// not allowed.
+    #[inline]
+    fn read_printable_utf8(
+        &mut self,
+        num_bytes: usize,
+        allowed: &[char],
+    ) -> Result<&'a str, Error> {
+        let s = self.read_utf8(num_bytes)?;
+        if s.chars().any(|c| c.is_control() && !allowed.contains(&c)) {
+            return Err(Error::InvalidValue);
+        }
+        Ok(s)
+    }
+
+    /// Reads NUL terminated UTF-8 string.
+    ///
+    /// The terminating NUL byte is consumed but not included in the string.
+    ///
+    /// Returns Ok(v) where v is a `&str` of bytes read before the NUL byte,
+    /// or Err(Error::EndOfInput) if the Reader encountered an end of the
+    /// input before the NUL byte, or Err(Error::ParseError) if UTF-8 parsing
+    /// failed.
+    #[inline]
+    fn read_cstr_utf8(&mut self) -> Result<&'a str, Error>
+    where
+        Self: Cursor<'a>,
+    {
+        let buf = self.read_bytes_until(0)?;
+        core::str::from_utf8(buf).map_err(|_| Error::ParseError)
+    }
+
+    /// Reads bytes as 7 bit ASCII string.
+    ///
+    /// Returns Ok(v) where v is a `&str` of bytes read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading, or Err(Error::InvalidValue) if a byte is greater than
+    /// 0x7F.
+    #[inline]
+    fn read_ascii(&mut self, num_bytes: usize) -> Result<&'a str, Error> {
+        let buf = self.read_bytes_less_safe(num_bytes)?;
+        if !buf.is_ascii() {
+            return Err(Error::InvalidValue);
+        }
+        // ASCII is always valid UTF-8.
+        core::str::from_utf8(buf).map_err(|_| Error::InvalidValue)
+    }
+
+    /// Reads Unicode scalar value stored as 32 bit unsigned integer in
+    /// big endian.
+    ///
+    /// Returns Ok(v) where v is the `char` read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading, or
+    /// Err(Error::InvalidValue) if the value is a surrogate (0xD800 to
+    /// 0xDFFF) or greater than 0x10FFFF.
+    #[inline]
+    fn read_char_u32be(&mut self) -> Result<char, Error> {
+        char::from_u32(self.read_u32be()?).ok_or(Error::InvalidValue)
+    }
+
+    /// Reads Unicode scalar value stored as 32 bit unsigned integer in
+    /// little endian.
+    ///
+    /// Returns Ok(v) where v is the `char` read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading, or
+    /// Err(Error::InvalidValue) if the value is a surrogate (0xD800 to
+    /// 0xDFFF) or greater than 0x10FFFF.
+    #[inline]
+    fn read_char_u32le(&mut self) -> Result<char, Error> {
+        char::from_u32(self.read_u32le()?).ok_or(Error::InvalidValue)
+    }
+
+    /// Reads a list of NUL terminated UTF-8 strings.
+    ///
+    /// Strings are read until an empty string, i.e. two consecutive NUL
+    /// bytes, is encountered. The terminating empty string is consumed but
+    /// not included in the list.
+    ///
+    /// Returns Ok(v) where v is a `Vec<&str>` of strings read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// before the terminating empty string, or Err(Error::ParseError) if UTF-8
+    /// parsing failed.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn read_cstr_list(&mut self) -> Result<Vec<&'a str>, Error>
+    where
+        Self: Cursor<'a>,
+    {
+        let mut list = Vec::new();
+        loop {
+            let buf = self.read_bytes_until(0)?;
+            if buf.is_empty() {
+                return Ok(list);
+            }
+            list.push(core::str::from_utf8(buf)?);
+        }
+    }
+
+    /// Reads a list of 32 bit unsigned integers in Big Endian byte order
+    /// terminated by `sentinel`.
+    ///
+    /// The sentinel is consumed but not included in the list.
+    ///
+    /// Returns Ok(v) where v is a `Vec<u32>` of values read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// before the sentinel.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn read_until_sentinel_u32be(&mut self, sentinel: u32) -> Result<Vec<u32>, Error> {
+        let mut list = Vec::new();
+        loop {
+            let value = self.read_u32be()?;
+            if value == sentinel {
+                return Ok(list);
+            }
+            list.push(value);
+        }
+    }
+
+    /// Reads a list of `count` UTF-8 strings each prefixed with its length
+    /// in bytes as unsigned LEB128, like Protocol Buffers repeated `string`
+    /// fields.
+    ///
+    /// The list grows as the strings are read instead of allocating room for
+    /// `count` strings up front, so an untrusted `count` can't cause a large
+    /// allocation.
+    ///
+    /// Returns Ok(v) where v is a `Vec<&str>` of strings read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading, or Err(Error::ParseError) if a length doesn't fit in
+    /// `usize` or UTF-8 parsing failed.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn read_varint_prefixed_utf8_list(&mut self, count: usize) -> Result<Vec<&'a str>, Error> {
+        let mut list = Vec::new();
+        for _ in 0..count {
+            let len = varint::read_uleb128_u64(self)?;
+            let len = usize::try_from(len).map_err(|_| Error::ParseError)?;
+            list.push(self.read_utf8(len)?);
+        }
+        Ok(list)
+    }
+
+    /// Reads records until the end of the input, each prefixed with its
+    /// length in bytes as unsigned LEB128.
+    ///
+    /// Returns Ok(v) where v is a `Vec<&[u8]>` of records read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// in the middle of a record, or Err(Error::ParseError) if a length is
+    /// malformed, or Err(Error::InvalidLength) if a record is longer than
+    /// `max_record` bytes.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn read_length_delimited_records(&mut self, max_record: usize) -> Result<Vec<&'a [u8]>, Error>
+    where
+        Self: Cursor<'a>,
+    {
+        self.read_with(|input| {
+            let mut records = Vec::new();
+            while !input.at_end() {
+                let len = varint::read_uleb128_u64(input)?;
+                let len = usize::try_from(len)
+                    .ok()
+                    .filter(|&len| len <= max_record)
+                    .ok_or(Error::InvalidLength)?;
+                records.push(input.read_bytes_less_safe(len)?);
+            }
+            Ok(records)
+        })
+    }
+
+    /// Reads unsigned LEB128 encoded 64 bit integers until the end of the
+    /// input, like a Protocol Buffers packed repeated field.
+    ///
+    /// Both the number of values and the number of bytes they are encoded in
+    /// are limited, so untrusted input can't make the caller process more
+    /// than it expects.
+    ///
+    /// Returns Ok(v) where v is a `Vec<u64>` of values read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// in the middle of a value, or Err(Error::ParseError) if a value doesn't
+    /// fit in `u64`, or Err(Error::InvalidLength) if there are more than
+    /// `max_count` values or they take more than `max_bytes` bytes.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn read_uleb128_vec_bounded(
+        &mut self,
+        max_count: usize,
+        max_bytes: usize,
+    ) -> Result<Vec<u64>, Error>
+    where
+        Self: Cursor<'a>,
+    {
+        self.read_with(|input| {
+            let mut values = Vec::new();
+            let mut bytes_read = 0;
+            while !input.at_end() {
+                if values.len() == max_count {
+                    return Err(Error::InvalidLength);
+                }
+                let (bytes, value) = input.read_partial(varint::read_uleb128_u64)?;
+                bytes_read += bytes.len();
+                if bytes_read > max_bytes {
+                    return Err(Error::InvalidLength);
+                }
+                values.push(value);
+            }
+            Ok(values)
+        })
+    }
+
+    /// Reads bytes as UTF-16 String.
+    ///
+    /// Length is the amount of bytes to read, not the amount of UTF-16
+    /// characters. Length should be even number and Err(Error::ParseError) is
+    /// returned if it's odd.
+    ///
+    /// Read bytes are validated to be valid UTF-16 by
+    /// [`String::from_utf16`](https://doc.rust-lang.org/std/string/struct.String.html#method.from_utf16)
+    /// method.
+    ///
+    /// Returns Ok(v) where v is a `String` of bytes read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading, or Err(Error::ParseError) if UTF-8 parsing failed.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn read_utf16(&mut self, num_bytes: usize) -> Result<String, Error> {
+        read_utf16_endian(self, num_bytes, Endianness::Big)
+    }
+
+    /// Reads bytes as UTF-16 String in little endian.
+    ///
+    /// Works like [ReaderExt::read_utf16], but the code units are in little
+    /// endian as in most UTF-16 data, like Windows APIs and many file
+    /// formats.
+    ///
+    /// Returns Ok(v) where v is a `String` of bytes read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading, or Err(Error::ParseError) if the length is odd or
+    /// UTF-16 parsing failed.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn read_utf16le(&mut self, num_bytes: usize) -> Result<String, Error> {
+        read_utf16_endian(self, num_bytes, Endianness::Little)
+    }
+
+    /// Reads bytes as UTF-16 String in the byte order given by a byte order
+    /// mark (BOM).
+    ///
+    /// Length is the amount of bytes to read, including the two bytes of the
+    /// BOM. `FE FF` selects big endian and `FF FE` little endian. The BOM is
+    /// not included in the String. Text without a BOM is rejected rather than
+    /// guessed, see [ReaderExt::read_text_with_bom] for that.
+    ///
+    /// Returns Ok(v) where v is a `String` of bytes read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading, or Err(Error::ParseError) if the BOM is missing, the
+    /// length is odd or UTF-16 parsing failed.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn read_utf16_with_bom(&mut self, num_bytes: usize) -> Result<String, Error> {
+        let text_len = num_bytes.checked_sub(2).ok_or(Error::ParseError)?;
+        match self.read_u16be()? {
+            0xFEFF => self.read_utf16(text_len),
+            0xFFFE => self.read_utf16le(text_len),
+            _ => Err(Error::ParseError),
+        }
+    }
+
+    /// Reads Unicode text of unknown encoding as String.
+    ///
+    /// Length is the amount of bytes to read, including the byte order mark
+    /// (BOM). The leading BOM selects the encoding: `EF BB BF` for UTF-8,
+    /// `FE FF` and `FF FE` for UTF-16 and `00 00 FE FF` and `FF FE 00 00` for
+    /// UTF-32 in Big and Little Endian byte order. The BOM is not included in
+    /// the String. Text without a BOM is decoded as UTF-8.
+    ///
+    /// Returns Ok(v) where v is a `String` of bytes read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading, or Err(Error::ParseError) if decoding failed or the
+    /// length of the text isn't a multiple of its code unit.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn read_text_with_bom(&mut self, num_bytes: usize) -> Result<String, Error> {
+        let buf = self.read_bytes_less_safe(num_bytes)?;
+        let (text, unit, endianness) = match buf {
+            [0x00, 0x00, 0xFE, 0xFF, text @ ..] => (text, 4, Endianness::Big),
+            [0xFF, 0xFE, 0x00, 0x00, text @ ..] => (text, 4, Endianness::Little),
+            [0xFE, 0xFF, text @ ..] => (text, 2, Endianness::Big),
+            [0xFF, 0xFE, text @ ..] => (text, 2, Endianness::Little),
+            [0xEF, 0xBB, 0xBF, text @ ..] => (text, 1, Endianness::Big),
+            text => (text, 1, Endianness::Big),
+        };
+        if text.len() % unit != 0 {
+            return Err(Error::ParseError);
+        }
+        let units = text.chunks(unit).map(|chunk| {
+            let chunk = chunk.iter().map(|&b| u32::from(b));
+            match endianness {
+                Endianness::Big => chunk.fold(0, |acc, b| acc << 8 | b),
+                Endianness::Little => chunk.rev().fold(0, |acc, b| acc << 8 | b),
+            }
+        });
+        match unit {
+            4 => units
+                .map(|c| char::from_u32(c).ok_or(Error::ParseError))
+                .collect(),
+            // Units of two bytes always fit in u16.
+            2 => {
+                let units: Vec<u16> = units.map(|u| u as u16).collect();
+                String::from_utf16(&units).map_err(From::from)
+            }
+            _ => Ok(core::str::from_utf8(text)?.to_owned()),
+        }
+    }
+
+    /// Reads telephony binary-coded decimal (TBCD) digits as String.
+    ///
+    /// Each byte holds two digits, the low nibble being the first one. Nibble
+    /// values 0xA to 0xE are mapped to characters `*`, `#`, `a`, `b` and `c`.
+    /// Nibble 0xF is a filler marking the end of the number, and only fillers
+    /// may follow it.
+    ///
+    /// Returns Ok(v) where v is a `String` of digits read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading, or Err(Error::InvalidValue) if a digit follows the
+    /// filler.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn read_tbcd(&mut self, num_bytes: usize) -> Result<String, Error> {
+        const DIGITS: &[u8; 15] = b"0123456789*#abc";
+        let buf = self.read_bytes_less_safe(num_bytes)?;
+        let mut digits = String::with_capacity(num_bytes * 2);
+        let mut ended = false;
+        for &b in buf {
+            for nibble in [b & 0x0F, b >> 4] {
+                match DIGITS.get(usize::from(nibble)) {
+                    Some(_) if ended => return Err(Error::InvalidValue),
+                    Some(&digit) => digits.push(char::from(digit)),
+                    None => ended = true,
+                }
+            }
+        }
+        Ok(digits)
+    }
+
+    /// Reads packed boolean flags.
+    ///
+    /// Reads `count` flags packed eight to a byte, most significant bit
+    /// first: the first flag is bit 7 of the first byte, the ninth flag is
+    /// bit 7 of the second byte. Padding bits after the last flag are
+    /// ignored.
+    ///
+    /// Returns Ok(v) where v is a `Vec<bool>` of `count` flags, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn read_packed_bools(&mut self, count: usize) -> Result<Vec<bool>, Error> {
+        let buf = self.read_bytes_less_safe(count.div_ceil(8))?;
+        Ok((0..count)
+            .map(|i| buf[i / 8] & (0x80 >> (i % 8)) != 0)
+            .collect())
+    }
+
+    /// Reads a presence bitmap of `num_bits` bits.
+    ///
+    /// This is [ReaderExt::read_packed_bools] under the name used by record
+    /// formats where each bit tells whether a corresponding optional field is
+    /// present.
+    ///
+    /// Returns Ok(v) where v is a `Vec<bool>` of `num_bits` bits, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn read_bitmap(&mut self, num_bits: usize) -> Result<Vec<bool>, Error> {
+        self.read_packed_bools(num_bits)
+    }
+
+    /// Reads a sequence of named bit fields.
+    ///
+    /// Each entry of `specs` is the name and width in bits (at most 64) of a
+    /// field. Fields are read most significant bit first and may cross byte
+    /// boundaries. Only as many bytes as the fields cover are read, and
+    /// padding bits after the last field are ignored.
+    ///
+    /// Returns Ok(v) where v is a `Vec` of names and values of the fields,
+    /// or Err(Error::EndOfInput) if the Reader encountered an end of the
+    /// input while reading, or Err(Error::ParseError) if a field is wider
+    /// than 64 bits.
+    ///
+    /// ```
+    /// use untrusted::{Input, Reader};
+    /// use untrustended::ReaderExt;
+    ///
+    /// let buf = [0x45, 0x00];
+    /// let mut reader = Reader::new(Input::from(&buf));
+    /// let fields = reader.read_fields(&[("version", 4), ("ihl", 4)]).unwrap();
+    /// assert_eq!(fields, vec![("version", 4), ("ihl", 5)]);
+    /// assert_eq!(reader.read_u8(), Ok(0));
+    /// ```
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn read_fields(
+        &mut self,
+        specs: &[(&'static str, u32)],
+    ) -> Result<Vec<(&'static str, u64)>, Error> {
+        let mut total_bits = 0usize;
+        for &(_, width) in specs {
+            if width > 64 {
+                return Err(Error::ParseError);
+            }
+            total_bits = total_bits
+                .checked_add(width as usize)
+                .ok_or(Error::ParseError)?;
+        }
+        let buf = self.read_bytes_less_safe(total_bits.div_ceil(8))?;
+        let mut bits = BitReader::new(buf);
+        specs
+            .iter()
+            .map(|&(name, width)| Ok((name, bits.read_bits(width as u8)?)))
+            .collect()
+    }
+
+    /// Reads `N` records stored as structure of arrays.
+    ///
+    /// Columnar layouts store all values of the first field, then all values
+    /// of the second field and so on. For each function in `fields`, in
+    /// order, the function is called `N` times to read a column.
+    ///
+    /// Returns Ok(v) where v is a `Vec` with one column of `N` values per
+    /// field, or the first error returned by a field function.
+    ///
+    /// ```
+    /// use untrusted::{Input, Reader};
+    /// use untrustended::{Error, ReaderExt};
+    ///
+    /// fn x(input: &mut Reader) -> Result<u64, Error> {
+    ///     input.read_u8().map(u64::from)
+    /// }
+    /// fn y(input: &mut Reader) -> Result<u64, Error> {
+    ///     input.read_u16be().map(u64::from)
+    /// }
+    ///
+    /// let buf = [1, 2, 0x00, 0x03, 0x00, 0x04];
+    /// let mut reader = Reader::new(Input::from(&buf));
+    /// let columns = reader.read_soa::<2>(&[x, y]).unwrap();
+    /// assert_eq!(columns, vec![vec![1, 2], vec![3, 4]]);
+    /// ```
+    #[inline]
+    #[cfg(feature = "alloc")]
+    fn read_soa<const N: usize>(&mut self, fields: &[ColumnReader]) -> Result<Vec<Vec<u64>>, Error>
+    where
+        Self: Cursor<'a>,
+    {
+        self.read_with(|input| {
+            fields
+                .iter()
+                .map(|field| (0..N).map(|_| field(input)).collect())
+                .collect()
+        })
+    }
+
+    /// Reads MAC address (EUI-48) in wire order.
+    ///
+    /// The first byte of the array is the most significant octet, the one
+    /// written first in the usual `00:1a:2b:3c:4d:5e` notation.
+    ///
+    /// Returns Ok(v) where v is a `[u8; 6]` of bytes read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    fn read_mac_addr(&mut self) -> Result<[u8; 6], Error> {
+        self.read_array()
+    }
+
+    /// Reads EUI-64 identifier in wire order.
+    ///
+    /// Returns Ok(v) where v is a `[u8; 8]` of bytes read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    fn read_eui64(&mut self) -> Result<[u8; 8], Error> {
+        self.read_array()
+    }
+
+    /// Reads RFC 4122 UUID in wire order.
+    ///
+    /// All fields are in big endian, so the bytes are in the order of the
+    /// usual `00112233-4455-6677-8899-aabbccddeeff` notation and can be
+    /// given as they are to, for example, `uuid::Uuid::from_bytes()`.
+    ///
+    /// Returns Ok(v) where v is a `[u8; 16]` of bytes read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    fn read_uuid(&mut self) -> Result<[u8; 16], Error> {
+        self.read_array()
+    }
+
+    /// Reads UUID in the mixed endian layout of Microsoft GUIDs.
+    ///
+    /// The first three fields (32, 16 and 16 bits) are in little endian and
+    /// the last 8 bytes are as they are. The result is in the same order as
+    /// returned by [ReaderExt::read_uuid].
+    ///
+    /// Returns Ok(v) where v is a `[u8; 16]` of bytes read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    fn read_uuid_mixed_endian(&mut self) -> Result<[u8; 16], Error> {
+        let buf: [u8; 16] = self.read_array()?;
+        let mut uuid = buf;
+        uuid[0..4].copy_from_slice(&[buf[3], buf[2], buf[1], buf[0]]);
+        uuid[4..6].copy_from_slice(&[buf[5], buf[4]]);
+        uuid[6..8].copy_from_slice(&[buf[7], buf[6]]);
+        Ok(uuid)
+    }
+
+    /// Reads the eight 16 bit groups of an IPv6 address in big endian.
+    ///
+    /// Unlike [ReaderExt::read_ipv6addr] this doesn't need `std::net`, and
+    /// returns the same groups as `Ipv6Addr::segments()`.
+    ///
+    /// Returns Ok(v) where v is a `[u16; 8]` of groups read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading.
+    #[inline]
+    fn read_ipv6_segments_be(&mut self) -> Result<[u16; 8], Error> {
+        let mut segments = [0u16; 8];
+        for segment in &mut segments {
+            *segment = self.read_u16be()?;
+        }
+        Ok(segments)
+    }
+
+    /// Reads IPv4 address in big endian format.
+    ///
+    /// Returns Ok(v) where v is a `Ipv4Addr`, or Err(Error::EndOfInput) if the
+    /// Reader encountered an end of the input while reading, or
+    /// Err(Error::ParseError) if parsing of address failed.
+    #[inline]
+    #[cfg(feature = "use_std")]
+    fn read_ipv4addr(&mut self) -> Result<Ipv4Addr, Error> {
+        self.read_u32be().map(Ipv4Addr::from)
+    }
+
+    /// Reads IPv6 address in big endian format.
+    ///
+    /// Returns Ok(v) where v is a `Ipv6Addr`, or Err(Error::EndOfInput) if the
+    /// Reader encountered an end of the input while reading, or
+    /// Err(Error::ParseError) if parsing of address failed.
+    #[inline]
+    #[cfg(feature = "use_std")]
+    fn read_ipv6addr(&mut self) -> Result<Ipv6Addr, Error> {
+        self.read_u128be().map(Ipv6Addr::from)
+    }
+
+    /// Reads IPv4 address followed by 16 bit port number, both in big
+    /// endian format.
+    ///
+    /// Returns Ok(v) where v is a `SocketAddrV4`, or Err(Error::EndOfInput)
+    /// if the Reader encountered an end of the input while reading.
+    #[inline]
+    #[cfg(feature = "use_std")]
+    fn read_socketaddr_v4(&mut self) -> Result<SocketAddrV4, Error> {
+        self.read_be()
+    }
+
+    /// Reads IPv6 address followed by 16 bit port number, both in big
+    /// endian format.
+    ///
+    /// Flow information and scope ID are not part of the input and are set
+    /// to zero.
+    ///
+    /// Returns Ok(v) where v is a `SocketAddrV6`, or Err(Error::EndOfInput)
+    /// if the Reader encountered an end of the input while reading.
+    #[inline]
+    #[cfg(feature = "use_std")]
+    fn read_socketaddr_v6(&mut self) -> Result<SocketAddrV6, Error> {
+        self.read_be()
+    }
+}
+
+impl<'a> ReaderExt<'a> for Reader<'a> {
+    #[inline]
+    fn read_byte(&mut self) -> Result<u8, EndOfInput> {
+        self.read_byte()
+    }
+
+    #[inline]
+    fn read_bytes(&mut self, num_bytes: usize) -> Result<Input<'a>, EndOfInput> {
+        self.read_bytes(num_bytes)
+    }
+
+    #[inline]
+    fn read_be<T: FromReader>(&mut self) -> Result<T, Error> {
+        FromReader::read_be(self)
+    }
+
+    #[inline]
+    fn read_le<T: FromReader>(&mut self) -> Result<T, Error> {
+        FromReader::read_le(self)
+    }
+}
+
+impl<'a> Cursor<'a> for Reader<'a> {
+    #[inline]
+    fn read_partial<F, R>(&mut self, read: F) -> Result<(Input<'a>, R), Error>
+    where
+        F: FnOnce(&mut Self) -> Result<R, Error>,
+    {
+        self.read_partial(read)
+    }
+
+    #[inline]
+    fn read_with<F, R>(&mut self, read: F) -> Result<R, Error>
+    where
+        F: FnOnce(&mut Reader<'a>) -> Result<R, Error>,
+    {
+        read(self)
+    }
+}
+
+mod sealed {
+    use untrusted::{Input, Reader};
+
+    use crate::Error;
+
+    /// Access to the input which some [ReaderExt](crate::ReaderExt) methods
+    /// need beyond reading bytes.
+    ///
+    /// This is implemented only for untrusted's `Reader` and
+    /// [TrackingReader](crate::TrackingReader), so that `ReaderExt` can be
+    /// implemented elsewhere without it. `ReaderExt` methods needing it
+    /// aren't available for such implementations.
+    pub trait Cursor<'a> {
+        /// Calls `read()` with this reader. On success, returns a pair
+        /// `(bytes_read, r)` where `bytes_read` is what `read()` consumed and
+        /// `r` is `read()`'s return value.
+        fn read_partial<F, R>(&mut self, read: F) -> Result<(Input<'a>, R), Error>
+        where
+            F: FnOnce(&mut Self) -> Result<R, Error>;
+
+        /// Calls `read()` with an untrusted `Reader` positioned at the
+        /// current position of this reader, and advances this reader by the
+        /// bytes `read()` consumed.
+        ///
+        /// This allows using functions written for untrusted's `Reader`, like
+        /// [Readable::read](crate::Readable::read), with any reader.
+        fn read_with<F, R>(&mut self, read: F) -> Result<R, Error>
+        where
+            F: FnOnce(&mut Reader<'a>) -> Result<R, Error>;
+    }
+}
+
+/// Reads a prefix of `input` with `read` and returns the bytes left over.
+///
+/// Unlike `Input::read_all`, `read` doesn't need to consume all of the
+/// input. This suits parsing a header and handing the rest to another
+/// parser.
+///
+/// ```
+/// use untrusted::Input;
+/// use untrustended::{read_partial, Error, ReaderExt};
+///
+/// let buf = [0x00, 0x02, 0xAA, 0xBB];
+/// let (len, rest) = read_partial(Input::from(&buf), Error::EndOfInput, |input| {
+///     input.read_u16be()
+/// })?;
+/// assert_eq!(len, 2);
+/// assert_eq!(rest, [0xAA, 0xBB]);
+/// # Ok::<(), Error>(())
+/// ```
+///
+/// Returns Ok((v, rest)) where v is the value returned by `read` and rest a
+/// `&[u8]` of bytes left over, or `incomplete_read` if the input ended
+/// before `read` was done, or any other error returned by `read`.
+pub fn read_partial<'a, F, T>(
+    input: Input<'a>,
+    incomplete_read: Error,
+    read: F,
+) -> Result<(T, &'a [u8]), Error>
+where
+    F: FnOnce(&mut Reader<'a>) -> Result<T, Error>,
+{
+    let mut reader = Reader::new(input);
+    let value = read(&mut reader).map_err(|e| match e {
+        Error::EndOfInput => incomplete_read,
+        e => e,
+    })?;
+    let rest = reader.read_bytes_to_end().as_slice_less_safe();
+    Ok((value, rest))
+}
+
+/// Reads `num_bytes` bytes as UTF-16 String with code units in given byte
+/// order, for [ReaderExt::read_utf16] and [ReaderExt::read_utf16le].
+#[cfg(feature = "alloc")]
+fn read_utf16_endian<'a, R: ReaderExt<'a> + ?Sized>(
+    input: &mut R,
+    num_bytes: usize,
+    endian: Endianness,
+) -> Result<String, Error> {
+    if (num_bytes % 2) != 0 {
+        return Err(Error::ParseError);
+    }
+    let len16 = num_bytes / 2;
+    let mut buf: Vec<u16> = Vec::with_capacity(len16);
+    for _ in 0..len16 {
+        let b = input.read_u16(endian)?;
+        buf.push(b);
+    }
+    String::from_utf16(&buf).map_err(From::from)
+}
+
+/// Parses ASCII digits in given radix, ignoring NUL and space padding around
+/// them.
+fn parse_ascii_number(buf: &[u8], radix: u32) -> Result<u64, Error> {
+    let is_padding = |b: &u8| *b == 0 || *b == b' ';
+    let start = buf.iter().position(|b| !is_padding(b)).unwrap_or(buf.len());
+    let end = buf
+        .iter()
+        .rposition(|b| !is_padding(b))
+        .map_or(start, |i| i + 1);
+    let digits = &buf[start..end];
+    if digits.is_empty() {
+        return Err(Error::ParseError);
+    }
+    digits.iter().try_fold(0u64, |value, &b| {
+        let digit = char::from(b).to_digit(radix).ok_or(Error::ParseError)?;
+        value
+            .checked_mul(u64::from(radix))
+            .and_then(|v| v.checked_add(u64::from(digit)))
+            .ok_or(Error::ParseError)
+    })
+}
+
+/// Expands RGB565 packed color into 8 bit components by repeating the high
+/// bits of each component in its low bits.
+#[inline]
+fn rgb565_to_rgb(value: u16) -> [u8; 3] {
+    let r = ((value >> 11) & 0x1F) as u8;
+    let g = ((value >> 5) & 0x3F) as u8;
+    let b = (value & 0x1F) as u8;
+    [
+        (r << 3) | (r >> 2),
+        (g << 2) | (g >> 4),
+        (b << 3) | (b >> 2),
+    ]
+}
+
+/// A trait to abstract the idea of creating a new instance of a type from
+/// reading bytes out from `Reader`.
+pub trait FromReader: Sized {
+    /// Read as many bytes as needed to instantiate a type in Big Endian byte
+    /// order.
+    fn read_be(_: &mut Reader<'_>) -> Result<Self, Error>;
+
+    /// Read as many bytes as needed to instantiate a type in Little Endian byte
+    /// order.
+    fn read_le(_: &mut Reader<'_>) -> Result<Self, Error>;
+}
+
+macro_rules! read_unsigned {
+    ($type:ty) => {
+        #[inline]
+        fn read_be(reader: &mut Reader<'_>) -> Result<Self, Error> {
+            const LEN: usize = core::mem::size_of::<$type>();
+            let mut arr = [0u8; LEN];
+            let slice = reader.read_bytes(LEN)?.as_slice_less_safe();
+            arr.copy_from_slice(slice);
+            Ok(<$type>::from_be_bytes(arr))
+        }
+
+        #[inline]
+        fn read_le(reader: &mut Reader<'_>) -> Result<Self, Error> {
+            const LEN: usize = core::mem::size_of::<$type>();
+            let mut arr = [0u8; LEN];
+            let slice = reader.read_bytes(LEN)?.as_slice_less_safe();
+            arr.copy_from_slice(slice);
+            Ok(<$type>::from_le_bytes(arr))
+        }
+    };
+}
+
+macro_rules! read_signed {
+    ($type:ty) => {
+        #[inline]
+        fn read_be(reader: &mut Reader<'_>) -> Result<Self, Error> {
+            let r = reader.read_be::<$type>()?;
+            Ok(r as Self)
+        }
+
+        #[inline]
+        fn read_le(reader: &mut Reader<'_>) -> Result<Self, Error> {
+            let r = reader.read_le::<$type>()?;
+            Ok(r as Self)
+        }
+    };
+}
+
+impl FromReader for u8 {
+    #[inline]
+    fn read_be(reader: &mut Reader<'_>) -> Result<Self, Error> {
+        reader.read_byte().map_err(From::from)
+    }
+
+    #[inline]
+    fn read_le(reader: &mut Reader<'_>) -> Result<Self, Error> {
+        reader.read_byte().map_err(From::from)
+    }
+}
+
+impl FromReader for u16 {
+    read_unsigned!(u16);
+}
+impl FromReader for u32 {
+    read_unsigned!(u32);
+}
+impl FromReader for u64 {
+    read_unsigned!(u64);
+}
+impl FromReader for u128 {
+    read_unsigned!(u128);
+}
+
+impl FromReader for i8 {
+    read_signed!(u8);
+}
+impl FromReader for i16 {
+    read_signed!(u16);
+}
+impl FromReader for i32 {
+    read_signed!(u32);
+}
+impl FromReader for i64 {
+    read_signed!(u64);
+}
+impl FromReader for i128 {
+    read_signed!(u128);
+}
+
+macro_rules! read_float {
+    ($type:ty) => {
+        #[inline]
+        fn read_be(reader: &mut Reader<'_>) -> Result<Self, Error> {
+            reader.read_be::<$type>().map(Self::from_bits)
+        }
+
+        #[inline]
+        fn read_le(reader: &mut Reader<'_>) -> Result<Self, Error> {
+            reader.read_le::<$type>().map(Self::from_bits)
+        }
+    };
+}
+
+impl FromReader for f32 {
+    read_float!(u32);
+}
+impl FromReader for f64 {
+    read_float!(u64);
+}
+
+#[cfg(feature = "use_std")]
+impl FromReader for Ipv4Addr {
+    fn read_be(reader: &mut Reader<'_>) -> Result<Self, Error> {
+        reader.read_u32be().map(Ipv4Addr::from)
+    }
+
+    fn read_le(reader: &mut Reader<'_>) -> Result<Self, Error> {
+        reader.read_u32le().map(Ipv4Addr::from)
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl FromReader for Ipv6Addr {
+    fn read_be(reader: &mut Reader<'_>) -> Result<Self, Error> {
+        reader.read_u128be().map(Ipv6Addr::from)
+    }
+
+    fn read_le(reader: &mut Reader<'_>) -> Result<Self, Error> {
+        reader.read_u128le().map(Ipv6Addr::from)
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl FromReader for SocketAddrV4 {
+    fn read_be(reader: &mut Reader<'_>) -> Result<Self, Error> {
+        let ip = reader.read_be()?;
+        let port = reader.read_be()?;
+        Ok(SocketAddrV4::new(ip, port))
+    }
+
+    fn read_le(reader: &mut Reader<'_>) -> Result<Self, Error> {
+        let ip = reader.read_le()?;
+        let port = reader.read_le()?;
+        Ok(SocketAddrV4::new(ip, port))
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl FromReader for SocketAddrV6 {
+    fn read_be(reader: &mut Reader<'_>) -> Result<Self, Error> {
+        let ip = reader.read_be()?;
+        let port = reader.read_be()?;
+        Ok(SocketAddrV6::new(ip, port, 0, 0))
+    }
+
+    fn read_le(reader: &mut Reader<'_>) -> Result<Self, Error> {
+        let ip = reader.read_le()?;
+        let port = reader.read_le()?;
+        Ok(SocketAddrV6::new(ip, port, 0, 0))
+    }
+}
 
 /// Data structure that can be constructed by reading from [untrusted::Reader]
 ///
@@ -595,19 +2937,40 @@ pub trait Readable {
     /// Parses data from `input` and produces [Self::Output] instance from
     /// data read.
     fn read(input: &mut Reader<'_>) -> Result<Self::Output, Error>;
+
+    /// Parses data from `input` in Big Endian byte order.
+    ///
+    /// Data structures whose layout depends on byte order implement this
+    /// and [Readable::read_le]. By default calls [Readable::read].
+    #[inline]
+    fn read_be(input: &mut Reader<'_>) -> Result<Self::Output, Error> {
+        Self::read(input)
+    }
+
+    /// Parses data from `input` in Little Endian byte order.
+    ///
+    /// Data structures whose layout depends on byte order implement this
+    /// and [Readable::read_be]. By default calls [Readable::read].
+    #[inline]
+    fn read_le(input: &mut Reader<'_>) -> Result<Self::Output, Error> {
+        Self::read(input)
+    }
 }
 
 mod error {
+    #[cfg(feature = "alloc")]
+    use alloc::string::FromUtf16Error;
+    use core::str::Utf8Error;
     #[cfg(feature = "use_std")]
     use std::fmt;
-    #[cfg(feature = "use_std")]
-    use std::str::Utf8Error;
-    #[cfg(feature = "use_std")]
-    use std::string::FromUtf16Error;
     use untrusted::EndOfInput;
 
     /// Possible errors raised by `ReaderExt`.
+    ///
+    /// New variants may be added in minor releases, so matches on `Error`
+    /// need a wildcard arm.
     #[derive(Debug, PartialEq)]
+    #[non_exhaustive]
     pub enum Error {
         /// The error type used to indicate the end of the input was reached
         /// before the operation could be completed.
@@ -618,13 +2981,55 @@ mod error {
         /// The error type indicating that while data parsed was syntactically
         /// correct, the value parsed vas invalid in this context.
         InvalidValue,
+        /// The error type indicating that a length read from the input
+        /// exceeds the limit given by the caller.
+        InvalidLength,
+        /// The error type indicating that the end of the input was reached
+        /// with `available` of the `needed` bytes remaining.
+        Incomplete {
+            /// Number of bytes the read needed.
+            needed: usize,
+            /// Number of bytes remaining in the input.
+            available: usize,
+        },
+        /// The error type indicating that a length read from the input
+        /// promised `needed` bytes while only `available` bytes remained.
+        ///
+        /// Returned by the length-prefixed reads of
+        /// [TrackingReader](crate::TrackingReader), which knows how many
+        /// bytes remain.
+        LengthExceedsInput {
+            /// Number of bytes the length promised.
+            needed: usize,
+            /// Number of bytes remaining in the input.
+            available: usize,
+        },
         /// Unknown error occured.
         UnknownError,
     }
 
+    /// Error together with the offset in the input where reading failed.
+    ///
+    /// Returned by [TrackingReader::read_all_with_offset](crate::TrackingReader::read_all_with_offset).
+    #[derive(Debug, PartialEq)]
+    pub struct OffsetError {
+        /// Number of bytes successfully read from the beginning of the input
+        /// before the error.
+        pub offset: usize,
+        /// The error which stopped reading.
+        pub error: Error,
+    }
+
     #[cfg(feature = "use_std")]
     impl std::error::Error for Error {}
 
+    #[cfg(feature = "use_std")]
+    impl std::error::Error for OffsetError {
+        fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+            Some(&self.error)
+        }
+    }
+
     #[cfg(feature = "use_std")]
     impl fmt::Display for Error {
         fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -632,28 +3037,62 @@ mod error {
                 Error::EndOfInput => f.write_str("end of input was reached unexpectedly"),
                 Error::ParseError => f.write_str("failed to parse data into a more specific type"),
                 Error::InvalidValue => f.write_str("parsed data contained invalid value"),
+                Error::InvalidLength => f.write_str("parsed length exceeded the allowed limit"),
+                Error::Incomplete { needed, available } => write!(
+                    f,
+                    "end of input was reached with {available} of {needed} bytes available"
+                ),
+                Error::LengthExceedsInput { needed, available } => write!(
+                    f,
+                    "length of {needed} bytes exceeded the {available} bytes remaining"
+                ),
                 Error::UnknownError => f.write_str("reading failed with an unknown error"),
             }
         }
     }
 
+    #[cfg(feature = "use_std")]
+    impl fmt::Display for OffsetError {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "{} at offset {}", self.error, self.offset)
+        }
+    }
+
     impl From<EndOfInput> for Error {
         fn from(_: EndOfInput) -> Self {
             Error::EndOfInput
         }
     }
 
-    #[cfg(feature = "use_std")]
     impl From<Utf8Error> for Error {
         fn from(_: Utf8Error) -> Self {
             Error::ParseError
         }
     }
 
-    #[cfg(feature = "use_std")]
+    #[cfg(feature = "alloc")]
     impl From<FromUtf16Error> for Error {
         fn from(_: FromUtf16Error) -> Self {
             Error::ParseError
         }
     }
+
+    /// Converts into `std::io::Error` of the matching `ErrorKind`, keeping
+    /// the `Error` as the inner error.
+    #[cfg(feature = "use_std")]
+    impl From<Error> for std::io::Error {
+        fn from(error: Error) -> Self {
+            use std::io::ErrorKind;
+
+            let kind = match error {
+                Error::EndOfInput | Error::Incomplete { .. } => ErrorKind::UnexpectedEof,
+                Error::ParseError
+                | Error::InvalidValue
+                | Error::InvalidLength
+                | Error::LengthExceedsInput { .. } => ErrorKind::InvalidData,
+                Error::UnknownError => ErrorKind::Other,
+            };
+            std::io::Error::new(kind, error)
+        }
+    }
 }
@@ -0,0 +1,77 @@
+//! Primitives for reading [MessagePack](https://github.com/msgpack/msgpack/blob/master/spec.md).
+//!
+//! Every value starts with a format byte which tells its type and, for
+//! small values, the value itself.
+//!
+//! Example:
+//!
+//! ```rust
+//! use untrusted::Input;
+//! use untrustended::{msgpack, Error};
+//!
+//! // 5, uint 16 1000, -1
+//! let buf = [0x05, 0xCD, 0x03, 0xE8, 0xFF];
+//! let values = Input::from(&buf)
+//!     .read_all(Error::UnknownError, |input| {
+//!         Ok([
+//!             msgpack::read_msgpack_int(input)?,
+//!             msgpack::read_msgpack_int(input)?,
+//!             msgpack::read_msgpack_int(input)?,
+//!         ])
+//!     })
+//!     .expect("read_all to succeed");
+//! assert_eq!(values, [5, 1000, -1]);
+//! ```
+
+use untrusted::Reader;
+
+use crate::{Error, ReaderExt};
+
+/// Reads an integer of any of the integer formats.
+///
+/// Positive and negative fixints are stored in the format byte. Other
+/// formats are followed by an unsigned (uint 8 to uint 64) or signed (int 8
+/// to int 64) integer in big endian.
+///
+/// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if the
+/// Reader encountered an end of the input while reading, or
+/// Err(Error::InvalidValue) if the format byte isn't an integer format or
+/// the value doesn't fit in `i64`.
+pub fn read_msgpack_int(input: &mut Reader<'_>) -> Result<i64, Error> {
+    let format = input.read_u8()?;
+    match format {
+        0x00..=0x7F => Ok(i64::from(format)),
+        0xE0..=0xFF => Ok(i64::from(format as i8)),
+        0xCC => Ok(i64::from(input.read_u8()?)),
+        0xCD => Ok(i64::from(input.read_u16be()?)),
+        0xCE => Ok(i64::from(input.read_u32be()?)),
+        0xCF => i64::try_from(input.read_u64be()?).map_err(|_| Error::InvalidValue),
+        0xD0 => Ok(i64::from(input.read_i8()?)),
+        0xD1 => Ok(i64::from(input.read_i16be()?)),
+        0xD2 => Ok(i64::from(input.read_i32be()?)),
+        0xD3 => input.read_i64be(),
+        _ => Err(Error::InvalidValue),
+    }
+}
+
+/// Reads a string of any of the string formats.
+///
+/// The length of a fixstr is stored in the low 5 bits of the format byte.
+/// Other formats are followed by the length as unsigned integer of 8 (str 8),
+/// 16 (str 16) or 32 (str 32) bits in big endian.
+///
+/// Returns Ok(v) where v is a `&str` of bytes read, or Err(Error::EndOfInput)
+/// if the Reader encountered an end of the input while reading, or
+/// Err(Error::InvalidValue) if the format byte isn't a string format, or
+/// Err(Error::ParseError) if UTF-8 parsing failed.
+pub fn read_msgpack_str<'a>(input: &mut Reader<'a>) -> Result<&'a str, Error> {
+    let len = match input.read_u8()? {
+        format @ 0xA0..=0xBF => usize::from(format & 0x1F),
+        0xD9 => usize::from(input.read_u8()?),
+        0xDA => usize::from(input.read_u16be()?),
+        0xDB => usize::try_from(input.read_u32be()?).map_err(|_| Error::EndOfInput)?,
+        _ => return Err(Error::InvalidValue),
+    };
+    let buf = input.read_bytes_less_safe(len)?;
+    core::str::from_utf8(buf).map_err(|_| Error::ParseError)
+}
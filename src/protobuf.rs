@@ -0,0 +1,104 @@
+//! Primitives for reading [Protocol Buffers](https://protobuf.dev/programming-guides/encoding/)
+//! wire format.
+//!
+//! Example:
+//!
+//! ```rust
+//! use untrusted::Input;
+//! use untrustended::{protobuf, Error, ReaderExt};
+//!
+//! // Field 1 (fixed32) = 42, field 2 (len) = "hi"
+//! let buf = [0x0D, 0x2A, 0x00, 0x00, 0x00, 0x12, 0x02, b'h', b'i'];
+//! let id = Input::from(&buf)
+//!     .read_all(Error::UnknownError, |input| {
+//!         let mut id = 0;
+//!         protobuf::read_protobuf_message(input, |field, wire_type, input| {
+//!             if field != 1 || wire_type != protobuf::WIRE_TYPE_I32 {
+//!                 return Ok(false);
+//!             }
+//!             id = input.read_u32le()?;
+//!             Ok(true)
+//!         })?;
+//!         Ok(id)
+//!     })
+//!     .expect("read_all to succeed");
+//! assert_eq!(id, 42);
+//! ```
+
+use untrusted::Reader;
+
+use crate::{varint, Error};
+
+/// Wire type of varint encoded fields.
+pub const WIRE_TYPE_VARINT: u8 = 0;
+/// Wire type of fixed 64 bit fields.
+pub const WIRE_TYPE_I64: u8 = 1;
+/// Wire type of length-delimited fields.
+pub const WIRE_TYPE_LEN: u8 = 2;
+/// Wire type of deprecated group start.
+pub const WIRE_TYPE_SGROUP: u8 = 3;
+/// Wire type of deprecated group end.
+pub const WIRE_TYPE_EGROUP: u8 = 4;
+/// Wire type of fixed 32 bit fields.
+pub const WIRE_TYPE_I32: u8 = 5;
+
+/// Reads field tag.
+///
+/// Returns Ok((field_number, wire_type)), or Err(Error::EndOfInput) if the
+/// Reader encountered an end of the input while reading, or
+/// Err(Error::ParseError) if the varint is malformed, or
+/// Err(Error::InvalidValue) if the field number is zero or doesn't fit in 29
+/// bits.
+pub fn read_protobuf_tag(input: &mut Reader<'_>) -> Result<(u32, u8), Error> {
+    let key = varint::read_uleb128_u64(input)?;
+    let field_number = key >> 3;
+    if field_number == 0 || field_number > 0x1FFF_FFFF {
+        return Err(Error::InvalidValue);
+    }
+    Ok((field_number as u32, (key & 0x07) as u8))
+}
+
+/// Skips the value of a field with given wire type.
+///
+/// Groups are deprecated and not supported.
+///
+/// Returns Ok(()) if the value was skipped, or Err(Error::EndOfInput) if the
+/// Reader encountered an end of the input while reading, or
+/// Err(Error::ParseError) if a varint is malformed, or
+/// Err(Error::InvalidValue) if the wire type is unknown or a group.
+pub fn skip_protobuf_field(input: &mut Reader<'_>, wire_type: u8) -> Result<(), Error> {
+    match wire_type {
+        WIRE_TYPE_VARINT => varint::read_uleb128_u64(input).map(|_| ()),
+        WIRE_TYPE_I64 => input.skip(8).map_err(From::from),
+        WIRE_TYPE_LEN => {
+            let len = varint::read_uleb128_u64(input)?;
+            let len = usize::try_from(len).map_err(|_| Error::EndOfInput)?;
+            input.skip(len).map_err(From::from)
+        }
+        WIRE_TYPE_I32 => input.skip(4).map_err(From::from),
+        _ => Err(Error::InvalidValue),
+    }
+}
+
+/// Reads fields of a message until the end of input.
+///
+/// For each field the tag is read and `f` is called with the field number,
+/// the wire type and the Reader positioned at the field's value. If `f`
+/// handles the field it must read the value and return Ok(true). If `f`
+/// returns Ok(false) without reading anything, the value is skipped with
+/// [skip_protobuf_field].
+///
+/// Returns Ok(()) when all fields were read, or the first error returned by
+/// reading a tag, `f` or skipping a field.
+pub fn read_protobuf_message<'a, F>(input: &mut Reader<'a>, mut f: F) -> Result<(), Error>
+where
+    F: FnMut(u32, u8, &mut Reader<'a>) -> Result<bool, Error>,
+{
+    while !input.at_end() {
+        let (field_number, wire_type) = read_protobuf_tag(input)?;
+        if !f(field_number, wire_type, input)? {
+            skip_protobuf_field(input, wire_type)?;
+        }
+    }
+    Ok(())
+}
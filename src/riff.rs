@@ -0,0 +1,72 @@
+//! Reading chunks of [RIFF](https://en.wikipedia.org/wiki/Resource_Interchange_File_Format)
+//! files like WAVE audio.
+//!
+//! Only the 16 bytes of "fmt " chunk fields common to all formats are
+//! parsed. Formats other than PCM, like `WAVE_FORMAT_EXTENSIBLE`, follow
+//! them with a `cbSize` and an extension, so read the chunk body with
+//! [read_partial](crate::read_partial) rather than `read_all`.
+//!
+//! Example:
+//!
+//! ```rust
+//! use untrusted::Input;
+//! use untrustended::{read_partial, riff, Error, ReaderExt};
+//!
+//! fn read_sample_rate(chunk: &[u8]) -> Result<u32, Error> {
+//!     Input::from(chunk).read_all(Error::UnknownError, |input| {
+//!         if input.read_bytes_less_safe(4)? != b"fmt " {
+//!             return Err(Error::InvalidValue);
+//!         }
+//!         let size = usize::try_from(input.read_u32le()?).map_err(|_| Error::ParseError)?;
+//!         let body = input.read_bytes(size)?;
+//!         let (fmt, _extension) = read_partial(body, Error::ParseError, riff::read_wav_fmt)?;
+//!         Ok(fmt.sample_rate)
+//!     })
+//! }
+//! ```
+
+use untrusted::Reader;
+
+use crate::{Error, ReaderExt};
+
+/// Fields of a WAVE "fmt " chunk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WavFmt {
+    /// Format of the samples, for example 1 for PCM or 3 for IEEE 754 float.
+    pub audio_format: u16,
+    /// Number of channels.
+    pub channels: u16,
+    /// Number of samples per second.
+    pub sample_rate: u32,
+    /// Number of bytes per second.
+    pub byte_rate: u32,
+    /// Number of bytes in a frame of one sample of each channel.
+    pub block_align: u16,
+    /// Number of bits in a sample.
+    pub bits_per_sample: u16,
+}
+
+/// Reads the body of a WAVE "fmt " chunk.
+///
+/// Reads the 16 bytes of fields common to all formats, all in little
+/// endian. Extension fields of formats other than PCM follow them and are
+/// left unread.
+///
+/// Returns Ok(v) where v is the format read, or Err(Error::EndOfInput) if
+/// the Reader encountered an end of the input while reading, or
+/// Err(Error::InvalidValue) if there are no channels or bits per sample is
+/// not 8, 16, 24, 32 or 64.
+pub fn read_wav_fmt(input: &mut Reader<'_>) -> Result<WavFmt, Error> {
+    let fmt = WavFmt {
+        audio_format: input.read_u16le()?,
+        channels: input.read_u16le()?,
+        sample_rate: input.read_u32le()?,
+        byte_rate: input.read_u32le()?,
+        block_align: input.read_u16le()?,
+        bits_per_sample: input.read_u16le()?,
+    };
+    if fmt.channels == 0 || ![8, 16, 24, 32, 64].contains(&fmt.bits_per_sample) {
+        return Err(Error::InvalidValue);
+    }
+    Ok(fmt)
+}
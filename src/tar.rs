@@ -0,0 +1,149 @@
+//! Reading [USTAR](https://pubs.opengroup.org/onlinepubs/9699919799/utilities/pax.html#tag_20_92_13_06)
+//! tar headers.
+//!
+//! Example:
+//!
+//! ```rust
+//! use untrusted::Input;
+//! use untrustended::{tar, Error};
+//!
+//! fn read_file_name(archive: &[u8]) -> Result<&[u8], Error> {
+//!     Input::from(archive).read_all(Error::UnknownError, |input| {
+//!         let header = tar::read_tar_header(input)?;
+//!         let size = usize::try_from(header.size).map_err(|_| Error::ParseError)?;
+//!         // File contents are padded to a multiple of the block size.
+//!         input.skip(size.div_ceil(tar::BLOCK_SIZE) * tar::BLOCK_SIZE)?;
+//!         input.skip_to_end();
+//!         Ok(header.name)
+//!     })
+//! }
+//! ```
+
+use untrusted::{Input, Reader};
+
+use crate::{parse_ascii_number, Error, ReaderExt};
+
+/// Size of a tar header and the unit in which file contents are padded.
+pub const BLOCK_SIZE: usize = 512;
+
+/// Offset of the checksum field in a header.
+const CHECKSUM_OFFSET: usize = 148;
+/// Length of the checksum field in a header.
+const CHECKSUM_LEN: usize = 8;
+
+/// Fields of a USTAR header.
+///
+/// String fields are borrowed from the input with their NUL padding
+/// removed. Numeric fields are parsed from octal.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TarHeader<'a> {
+    /// Name of the file, without [TarHeader::prefix].
+    pub name: &'a [u8],
+    /// File mode bits.
+    pub mode: u64,
+    /// Numeric user id of the owner.
+    pub uid: u64,
+    /// Numeric group id of the owner.
+    pub gid: u64,
+    /// Size of the file contents following the header.
+    pub size: u64,
+    /// Modification time in seconds since Unix epoch.
+    pub mtime: u64,
+    /// Type of the entry, for example `b'0'` for regular file.
+    pub typeflag: u8,
+    /// Target of a link.
+    pub linkname: &'a [u8],
+    /// Magic, `b"ustar\0"` for POSIX or `b"ustar "` for GNU tar.
+    pub magic: &'a [u8],
+    /// Version of the format.
+    pub version: &'a [u8],
+    /// User name of the owner.
+    pub uname: &'a [u8],
+    /// Group name of the owner.
+    pub gname: &'a [u8],
+    /// Major number of a device file, zero if the field is empty.
+    pub devmajor: u64,
+    /// Minor number of a device file, zero if the field is empty.
+    pub devminor: u64,
+    /// Directory prefix of [TarHeader::name].
+    pub prefix: &'a [u8],
+}
+
+/// Reads a 512 byte USTAR header block.
+///
+/// The header checksum is the sum of all bytes of the header, with the
+/// bytes of the checksum field itself counted as spaces.
+///
+/// Returns Ok(v) where v is the header read, or Err(Error::EndOfInput) if the
+/// Reader encountered an end of the input while reading, or
+/// Err(Error::ParseError) if a numeric field is malformed, or
+/// Err(Error::InvalidValue) if the magic isn't "ustar" or the checksum
+/// doesn't match.
+pub fn read_tar_header<'a>(input: &mut Reader<'a>) -> Result<TarHeader<'a>, Error> {
+    let block = input.read_bytes_less_safe(BLOCK_SIZE)?;
+    let mut fields = Reader::new(Input::from(block));
+    let name = read_str(&mut fields, 100)?;
+    let mode = fields.read_ascii_octal(8)?;
+    let uid = fields.read_ascii_octal(8)?;
+    let gid = fields.read_ascii_octal(8)?;
+    let size = fields.read_ascii_octal(12)?;
+    let mtime = fields.read_ascii_octal(12)?;
+    let checksum = fields.read_ascii_octal(CHECKSUM_LEN)?;
+    let typeflag = fields.read_u8()?;
+    let linkname = read_str(&mut fields, 100)?;
+    let magic = fields.read_bytes_less_safe(6)?;
+    let version = fields.read_bytes_less_safe(2)?;
+    let uname = read_str(&mut fields, 32)?;
+    let gname = read_str(&mut fields, 32)?;
+    let devmajor = read_dev(&mut fields)?;
+    let devminor = read_dev(&mut fields)?;
+    let prefix = read_str(&mut fields, 155)?;
+
+    if !magic.starts_with(b"ustar") || checksum != header_checksum(block) {
+        return Err(Error::InvalidValue);
+    }
+    Ok(TarHeader {
+        name,
+        mode,
+        uid,
+        gid,
+        size,
+        mtime,
+        typeflag,
+        linkname,
+        magic,
+        version,
+        uname,
+        gname,
+        devmajor,
+        devminor,
+        prefix,
+    })
+}
+
+/// Calculates the checksum of a header block.
+fn header_checksum(block: &[u8]) -> u64 {
+    let checksum_field = CHECKSUM_OFFSET..CHECKSUM_OFFSET + CHECKSUM_LEN;
+    block
+        .iter()
+        .enumerate()
+        .map(|(i, &b)| if checksum_field.contains(&i) { b' ' } else { b })
+        .map(u64::from)
+        .sum()
+}
+
+/// Reads a NUL padded string field.
+fn read_str<'a>(input: &mut Reader<'a>, len: usize) -> Result<&'a [u8], Error> {
+    let field = input.read_bytes_less_safe(len)?;
+    let end = field.iter().position(|&b| b == 0).unwrap_or(len);
+    Ok(&field[..end])
+}
+
+/// Reads a device number field, which is left empty by some archivers.
+fn read_dev(input: &mut Reader<'_>) -> Result<u64, Error> {
+    let field = input.read_bytes_less_safe(8)?;
+    if field.iter().all(|&b| b == 0 || b == b' ') {
+        return Ok(0);
+    }
+    parse_ascii_number(field, 8)
+}
@@ -0,0 +1,107 @@
+//! Primitives for reading [Apache Thrift](https://thrift.apache.org/)
+//! [compact protocol](https://github.com/apache/thrift/blob/master/doc/specs/thrift-compact-protocol.md).
+//!
+//! Example:
+//!
+//! ```rust
+//! use untrusted::Input;
+//! use untrustended::{thrift, Error};
+//!
+//! // Field 1 (i32) = -2, stop
+//! let buf = [0x15, 0x03, 0x00];
+//! let value = Input::from(&buf)
+//!     .read_all(Error::UnknownError, |input| {
+//!         let (delta, type_id) = thrift::read_compact_field_header(input)?;
+//!         assert_eq!((delta, type_id), (1, thrift::TYPE_I32));
+//!         let value = thrift::read_compact_i32(input)?;
+//!         assert_eq!(thrift::read_compact_field_header(input)?.1, thrift::TYPE_STOP);
+//!         Ok(value)
+//!     })
+//!     .expect("read_all to succeed");
+//! assert_eq!(value, -2);
+//! ```
+
+use untrusted::Reader;
+
+use crate::{varint, Error};
+
+/// Type of the stop field ending a struct.
+pub const TYPE_STOP: u8 = 0;
+/// Type of boolean field with value `true`.
+pub const TYPE_BOOLEAN_TRUE: u8 = 1;
+/// Type of boolean field with value `false`.
+pub const TYPE_BOOLEAN_FALSE: u8 = 2;
+/// Type of 8 bit integer fields.
+pub const TYPE_I8: u8 = 3;
+/// Type of 16 bit integer fields.
+pub const TYPE_I16: u8 = 4;
+/// Type of 32 bit integer fields.
+pub const TYPE_I32: u8 = 5;
+/// Type of 64 bit integer fields.
+pub const TYPE_I64: u8 = 6;
+/// Type of double precision floating point fields.
+pub const TYPE_DOUBLE: u8 = 7;
+/// Type of binary and string fields.
+pub const TYPE_BINARY: u8 = 8;
+/// Type of list fields.
+pub const TYPE_LIST: u8 = 9;
+/// Type of set fields.
+pub const TYPE_SET: u8 = 10;
+/// Type of map fields.
+pub const TYPE_MAP: u8 = 11;
+/// Type of struct fields.
+pub const TYPE_STRUCT: u8 = 12;
+/// Type of UUID fields.
+pub const TYPE_UUID: u8 = 13;
+
+/// Reads field header byte.
+///
+/// The high nibble of the header is the delta to the previous field id and
+/// the low nibble is the type of the field. The stop field ending a struct
+/// is returned as `(0, TYPE_STOP)`. A delta of zero with other types means
+/// the field id follows the header, to be read with [read_compact_i16].
+///
+/// Returns Ok((field_delta, type_id)), or Err(Error::EndOfInput) if the
+/// Reader encountered an end of the input while reading, or
+/// Err(Error::InvalidValue) if the header has a delta but type is stop.
+pub fn read_compact_field_header(input: &mut Reader<'_>) -> Result<(u8, u8), Error> {
+    let header = input.read_byte()?;
+    let (delta, type_id) = (header >> 4, header & 0x0F);
+    if type_id == TYPE_STOP && delta != 0 {
+        return Err(Error::InvalidValue);
+    }
+    Ok((delta, type_id))
+}
+
+/// Reads zigzag encoded 16 bit integer.
+///
+/// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if the
+/// Reader encountered an end of the input while reading, or
+/// Err(Error::ParseError) if the varint is malformed or doesn't fit in 16
+/// bits.
+pub fn read_compact_i16(input: &mut Reader<'_>) -> Result<i16, Error> {
+    let value = varint::read_uleb128_u64(input)?;
+    let value = u16::try_from(value).map_err(|_| Error::ParseError)?;
+    Ok(varint::zigzag_decode_u64(u64::from(value)) as i16)
+}
+
+/// Reads zigzag encoded 32 bit integer.
+///
+/// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if the
+/// Reader encountered an end of the input while reading, or
+/// Err(Error::ParseError) if the varint is malformed or doesn't fit in 32
+/// bits.
+pub fn read_compact_i32(input: &mut Reader<'_>) -> Result<i32, Error> {
+    let value = varint::read_uleb128_u64(input)?;
+    let value = u32::try_from(value).map_err(|_| Error::ParseError)?;
+    Ok(varint::zigzag_decode_u64(u64::from(value)) as i32)
+}
+
+/// Reads zigzag encoded 64 bit integer.
+///
+/// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if the
+/// Reader encountered an end of the input while reading, or
+/// Err(Error::ParseError) if the varint is malformed.
+pub fn read_compact_i64(input: &mut Reader<'_>) -> Result<i64, Error> {
+    varint::read_zigzag_i64(input)
+}
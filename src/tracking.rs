@@ -0,0 +1,446 @@
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String, vec::Vec};
+use untrusted::{EndOfInput, Input, Reader};
+
+use crate::sealed::Cursor;
+use crate::{varint, Error, FromReader, OffsetError, ReaderExt};
+
+/// A reader which keeps track of its position in the input.
+///
+/// Upstream [`Reader`](https://briansmith.org/rustdoc/untrusted/struct.Reader.html)
+/// doesn't expose how much of the input has been read. `TrackingReader`
+/// provides all the methods of [ReaderExt] and additionally methods which
+/// need to know the current position, like reading naturally aligned values.
+///
+/// # Example
+///
+/// ```
+/// use untrusted::Input;
+/// use untrustended::{ReaderExt, TrackingReader};
+///
+/// let buf = [0x01, 0xff, 0xff, 0xff, 0x00, 0x00, 0x00, 0x02];
+/// let mut reader = TrackingReader::new(Input::from(&buf));
+/// assert_eq!(reader.read_u8(), Ok(1));
+/// assert_eq!(reader.read_aligned_be::<u32>(), Ok(2));
+/// assert_eq!(reader.position(), 8);
+/// ```
+pub struct TrackingReader<'a> {
+    input: &'a [u8],
+    position: usize,
+}
+
+impl<'a> TrackingReader<'a> {
+    /// Construct a new TrackingReader for the given input.
+    #[inline]
+    pub fn new(input: Input<'a>) -> Self {
+        Self {
+            input: input.as_slice_less_safe(),
+            position: 0,
+        }
+    }
+
+    /// Reads all of `input` with `read`, reporting where reading stopped on
+    /// failure.
+    ///
+    /// Works like `Input::read_all` but on a `TrackingReader`. A failing
+    /// read leaves the position where it started, so the reported offset
+    /// points to the beginning of the value which couldn't be read.
+    ///
+    /// ```
+    /// use untrusted::Input;
+    /// use untrustended::{Error, OffsetError, ReaderExt, TrackingReader};
+    ///
+    /// let buf = [0x00, 0x01, 0x00, 0x00];
+    /// let result = TrackingReader::read_all_with_offset(
+    ///     Input::from(&buf),
+    ///     Error::UnknownError,
+    ///     |input| Ok((input.read_u16be()?, input.read_u32be()?)),
+    /// );
+    /// assert_eq!(
+    ///     result,
+    ///     Err(OffsetError { offset: 2, error: Error::EndOfInput })
+    /// );
+    /// ```
+    ///
+    /// Returns Ok(v) where v is the value returned by `read`, or
+    /// Err(OffsetError { offset, error }) where error is the error returned
+    /// by `read`, or `incomplete_read` if `read` didn't read all of the
+    /// input.
+    pub fn read_all_with_offset<F, R>(
+        input: Input<'a>,
+        incomplete_read: Error,
+        read: F,
+    ) -> Result<R, OffsetError>
+    where
+        F: FnOnce(&mut Self) -> Result<R, Error>,
+    {
+        let mut reader = Self::new(input);
+        let result = read(&mut reader);
+        let offset = reader.position();
+        match result {
+            Ok(r) if reader.at_end() => Ok(r),
+            Ok(_) => Err(OffsetError {
+                offset,
+                error: incomplete_read,
+            }),
+            Err(error) => Err(OffsetError { offset, error }),
+        }
+    }
+
+    /// Returns the number of bytes read from the beginning of the input.
+    #[inline]
+    pub fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns `true` if the reader is at the end of the input, and `false`
+    /// otherwise.
+    #[inline]
+    pub fn at_end(&self) -> bool {
+        self.position == self.input.len()
+    }
+
+    /// Returns the number of bytes left to read.
+    ///
+    /// Every successful read decreases the count by the number of bytes it
+    /// consumed, including the bytes of an `Input` returned by
+    /// [ReaderExt::read_bytes]. A read which fails without consuming, like a
+    /// short [ReaderExt::read_bytes], leaves the count unchanged.
+    ///
+    /// ```
+    /// use untrusted::Input;
+    /// use untrustended::{Error, ReaderExt, TrackingReader};
+    ///
+    /// let mut reader = TrackingReader::new(Input::from(&[0, 2, 1, 2]));
+    /// let header_len = reader.read_u16be()?;
+    /// if reader.bytes_remaining() < usize::from(header_len) {
+    ///     return Err(Error::EndOfInput);
+    /// }
+    /// # Ok::<(), Error>(())
+    /// ```
+    #[inline]
+    pub fn bytes_remaining(&self) -> usize {
+        self.remaining().len()
+    }
+
+    /// Reads naturally aligned value in Big Endian byte order.
+    ///
+    /// Before reading, skips padding bytes until the position is a multiple
+    /// of the size of `T` rounded up to a power of two (at most 8) from the
+    /// beginning of the input. The
+    /// contents of padding bytes are not checked.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
+    #[inline]
+    pub fn read_aligned_be<T: FromReader>(&mut self) -> Result<T, Error> {
+        self.skip_alignment::<T>()?;
+        self.read_be()
+    }
+
+    /// Reads naturally aligned value in Little Endian byte order.
+    ///
+    /// Before reading, skips padding bytes until the position is a multiple
+    /// of the size of `T` rounded up to a power of two (at most 8) from the
+    /// beginning of the input. The
+    /// contents of padding bytes are not checked.
+    ///
+    /// Returns Ok(v) where v is the value read, or Err(Error::EndOfInput) if
+    /// the Reader encountered an end of the input while reading.
+    #[inline]
+    pub fn read_aligned_le<T: FromReader>(&mut self) -> Result<T, Error> {
+        self.skip_alignment::<T>()?;
+        self.read_le()
+    }
+
+    /// Skips padding until the position is aligned to `alignment` bytes
+    /// relative to `start_offset`, the position where the message starts.
+    ///
+    /// Skips the minimal number of bytes, so nothing is skipped if the
+    /// position is already aligned. The contents of padding bytes are not
+    /// checked.
+    ///
+    /// ```
+    /// use untrusted::Input;
+    /// use untrustended::{Error, ReaderExt, TrackingReader};
+    ///
+    /// let buf = [0xff, 0x01, 0x00, 0x00, 0x00, 0x00, 0x02];
+    /// let mut reader = TrackingReader::new(Input::from(&buf));
+    /// reader.read_u8()?;
+    /// let start = reader.position();
+    /// reader.read_u8()?;
+    /// reader.align_to(4, start)?;
+    /// assert_eq!(reader.read_u16be(), Ok(2));
+    /// # Ok::<(), Error>(())
+    /// ```
+    ///
+    /// Returns Ok(()) if the padding was skipped, or Err(Error::EndOfInput)
+    /// if the Reader encountered an end of the input while reading, or
+    /// Err(Error::ParseError) if `alignment` isn't a power of two or
+    /// `start_offset` is past the current position.
+    pub fn align_to(&mut self, alignment: usize, start_offset: usize) -> Result<(), Error> {
+        if !alignment.is_power_of_two() {
+            return Err(Error::ParseError);
+        }
+        let offset = self
+            .position
+            .checked_sub(start_offset)
+            .ok_or(Error::ParseError)?;
+        let padding = offset.wrapping_neg() & (alignment - 1);
+        self.skip_bytes(padding)
+    }
+
+    /// Reads exactly `num_bytes` bytes.
+    ///
+    /// Unlike [ReaderExt::read_bytes], a short read reports how many bytes
+    /// were available, so a streaming caller can tell how much more data to
+    /// wait for. Nothing is consumed on a short read.
+    ///
+    /// Returns Ok(i) where i is an Input of bytes read, or
+    /// Err(Error::Incomplete { needed, available }) if fewer than `num_bytes`
+    /// bytes remain.
+    pub fn read_bytes_exact(&mut self, num_bytes: usize) -> Result<Input<'a>, Error> {
+        let available = self.bytes_remaining();
+        if num_bytes > available {
+            return Err(Error::Incomplete {
+                needed: num_bytes,
+                available,
+            });
+        }
+        Ok(self.read_bytes(num_bytes)?)
+    }
+
+    /// Returns the next byte without advancing the reader.
+    ///
+    /// Returns Ok(v) where v is the next byte, or Err(Error::EndOfInput) if
+    /// the Reader is at the end of the input.
+    #[inline]
+    pub fn peek_u8(&self) -> Result<u8, Error> {
+        self.remaining().first().copied().ok_or(Error::EndOfInput)
+    }
+
+    /// Returns 16 bit unsigned integer in big endian without advancing the
+    /// reader.
+    ///
+    /// Returns Ok(v) where v is the value peeked, or Err(Error::EndOfInput)
+    /// if fewer than two bytes remain.
+    #[inline]
+    pub fn peek_u16be(&self) -> Result<u16, Error> {
+        match self.remaining() {
+            [b0, b1, ..] => Ok(u16::from_be_bytes([*b0, *b1])),
+            _ => Err(Error::EndOfInput),
+        }
+    }
+
+    /// Decodes unsigned LEB128 encoded 64 bit integer without advancing the
+    /// reader.
+    ///
+    /// This allows checking a leading length or tag before deciding how to
+    /// read the rest.
+    ///
+    /// Returns Ok(v) where v is the value decoded, or Err(Error::EndOfInput)
+    /// if the Reader encountered an end of the input while decoding, or
+    /// Err(Error::ParseError) if the value doesn't fit in `u64`.
+    pub fn peek_uleb128_u64(&self) -> Result<u64, Error> {
+        let mut peek = TrackingReader {
+            input: self.input,
+            position: self.position,
+        };
+        varint::read_uleb128_u64(&mut peek)
+    }
+
+    /// Reads bytes while they match given predicate, but at most `max`
+    /// bytes.
+    ///
+    /// Reading stops before the first byte not matching `pred`, at the end of
+    /// the input, or after `max` bytes, whichever comes first. Reaching `max`
+    /// is not an error, so the caller must check if the run continues when
+    /// that matters. At most `max + 1` bytes are examined, which bounds the
+    /// work done on adversarial input.
+    ///
+    /// Returns Ok(v) where v is a `&[u8]` of matching bytes read, possibly
+    /// empty.
+    pub fn read_while_bounded<F>(&mut self, max: usize, pred: F) -> Result<&'a [u8], Error>
+    where
+        F: Fn(u8) -> bool,
+    {
+        let len = self
+            .remaining()
+            .iter()
+            .take(max)
+            .take_while(|&&b| pred(b))
+            .count();
+        self.read_bytes_less_safe(len)
+    }
+
+    /// Reads a table of `count` 32 bit offsets in Big Endian byte order.
+    ///
+    /// If `validate` is `true`, each offset must be within the input, i.e. at
+    /// most the length of the input given to [TrackingReader::new]. An
+    /// offset equal to the length refers to the end of the input.
+    ///
+    /// Returns Ok(v) where v is a `Vec<u32>` of offsets read, or
+    /// Err(Error::EndOfInput) if the Reader encountered an end of the input
+    /// while reading, or Err(Error::InvalidValue) if validation of an offset
+    /// failed.
+    #[cfg(feature = "alloc")]
+    pub fn read_offset_table_u32be(
+        &mut self,
+        count: usize,
+        validate: bool,
+    ) -> Result<Vec<u32>, Error> {
+        let mut offsets = Vec::new();
+        for _ in 0..count {
+            let offset = self.read_u32be()?;
+            if validate && usize::try_from(offset).map_or(true, |o| o > self.input.len()) {
+                return Err(Error::InvalidValue);
+            }
+            offsets.push(offset);
+        }
+        Ok(offsets)
+    }
+
+    /// Formats the unread input as a hex dump for diagnostics.
+    ///
+    /// The layout follows `xxd`: each line has the offset from the beginning
+    /// of the input, up to 16 bytes in hex grouped by two, and the same bytes
+    /// as ASCII with non-printable bytes shown as `.`.
+    ///
+    /// ```
+    /// use untrusted::Input;
+    /// use untrustended::TrackingReader;
+    ///
+    /// let reader = TrackingReader::new(Input::from(b"Hi!\n"));
+    /// assert_eq!(
+    ///     reader.remaining_hexdump(),
+    ///     "00000000: 4869 210a                                Hi!.\n"
+    /// );
+    /// ```
+    #[cfg(feature = "alloc")]
+    pub fn remaining_hexdump(&self) -> String {
+        let mut dump = String::new();
+        for (i, line) in self.remaining().chunks(16).enumerate() {
+            let offset = self.position + i * 16;
+            let hex: Vec<String> = line
+                .chunks(2)
+                .map(|group| group.iter().map(|b| format!("{b:02x}")).collect())
+                .collect();
+            let hex = hex.join(" ");
+            let ascii: String = line
+                .iter()
+                .map(|&b| match b {
+                    b' '..=b'~' => char::from(b),
+                    _ => '.',
+                })
+                .collect();
+            dump.push_str(&format!("{offset:08x}: {hex:<39}  {ascii}\n"));
+        }
+        dump
+    }
+
+    fn skip_alignment<T>(&mut self) -> Result<(), Error> {
+        let alignment = core::mem::size_of::<T>().next_power_of_two().min(8);
+        let padding = (alignment - self.position % alignment) % alignment;
+        self.read_bytes(padding)?;
+        Ok(())
+    }
+
+    fn remaining(&self) -> &'a [u8] {
+        self.input.get(self.position..).unwrap_or_default()
+    }
+
+    /// Reads `needed` bytes whose length was declared by the input itself.
+    ///
+    /// If fewer bytes remain, rewinds to `start`, where the length began.
+    fn read_declared_bytes(&mut self, start: usize, needed: usize) -> Result<&'a [u8], Error> {
+        let available = self.bytes_remaining();
+        if needed > available {
+            self.position = start;
+            return Err(Error::LengthExceedsInput { needed, available });
+        }
+        self.read_bytes_less_safe(needed)
+    }
+}
+
+impl<'a> ReaderExt<'a> for TrackingReader<'a> {
+    #[inline]
+    fn read_byte(&mut self) -> Result<u8, EndOfInput> {
+        let b = *self.input.get(self.position).ok_or(EndOfInput)?;
+        self.position += 1;
+        Ok(b)
+    }
+
+    #[inline]
+    fn read_bytes(&mut self, num_bytes: usize) -> Result<Input<'a>, EndOfInput> {
+        let end = self.position.checked_add(num_bytes).ok_or(EndOfInput)?;
+        let bytes = self.input.get(self.position..end).ok_or(EndOfInput)?;
+        self.position = end;
+        Ok(Input::from(bytes))
+    }
+
+    #[inline]
+    fn read_be<T: FromReader>(&mut self) -> Result<T, Error> {
+        self.read_with(T::read_be)
+    }
+
+    #[inline]
+    fn read_le<T: FromReader>(&mut self) -> Result<T, Error> {
+        self.read_with(T::read_le)
+    }
+
+    /// Like [ReaderExt::read_length_prefixed_u8], but returns
+    /// Err(Error::LengthExceedsInput { needed, available }) without advancing
+    /// the reader if the length exceeds the remaining input.
+    #[inline]
+    fn read_length_prefixed_u8(&mut self) -> Result<&'a [u8], Error> {
+        let start = self.position;
+        let len = self.read_u8()?;
+        self.read_declared_bytes(start, usize::from(len))
+    }
+
+    /// Like [ReaderExt::read_length_prefixed_u16be], but returns
+    /// Err(Error::LengthExceedsInput { needed, available }) without advancing
+    /// the reader if the length exceeds the remaining input.
+    #[inline]
+    fn read_length_prefixed_u16be(&mut self) -> Result<&'a [u8], Error> {
+        let start = self.position;
+        let len = self.read_u16be()?;
+        self.read_declared_bytes(start, usize::from(len))
+    }
+
+    /// Like [ReaderExt::read_length_prefixed_u32be], but returns
+    /// Err(Error::LengthExceedsInput { needed, available }) without advancing
+    /// the reader if the length exceeds the remaining input.
+    #[inline]
+    fn read_length_prefixed_u32be(&mut self) -> Result<&'a [u8], Error> {
+        let start = self.position;
+        let len = self.read_u32be()?;
+        // A length not fitting in usize can't fit in the input either.
+        let len = usize::try_from(len).unwrap_or(usize::MAX);
+        self.read_declared_bytes(start, len)
+    }
+}
+
+impl<'a> Cursor<'a> for TrackingReader<'a> {
+    #[inline]
+    fn read_partial<F, R>(&mut self, read: F) -> Result<(Input<'a>, R), Error>
+    where
+        F: FnOnce(&mut Self) -> Result<R, Error>,
+    {
+        let start = self.position;
+        let r = read(self)?;
+        let bytes_read = self.input.get(start..self.position).unwrap_or_default();
+        Ok((Input::from(bytes_read), r))
+    }
+
+    #[inline]
+    fn read_with<F, R>(&mut self, read: F) -> Result<R, Error>
+    where
+        F: FnOnce(&mut Reader<'a>) -> Result<R, Error>,
+    {
+        let mut reader = Reader::new(Input::from(self.remaining()));
+        let (bytes_read, r) = reader.read_partial(read)?;
+        self.position += bytes_read.len();
+        Ok(r)
+    }
+}
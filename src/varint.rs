@@ -0,0 +1,94 @@
+//! Shared decoding of variable-length integers.
+
+use crate::{Error, ReaderExt};
+
+/// Maximum number of bytes in a LEB128 encoded `u64`.
+const MAX_LEB128_U64_LEN: u32 = 10;
+
+/// Reads unsigned LEB128 encoded `u64`.
+///
+/// Redundant trailing zero groups are accepted as long as the encoding fits
+/// in ten bytes.
+pub(crate) fn read_uleb128_u64<'a, R>(input: &mut R) -> Result<u64, Error>
+where
+    R: ReaderExt<'a> + ?Sized,
+{
+    let mut result = 0u64;
+    for i in 0..MAX_LEB128_U64_LEN {
+        let byte = input.read_u8()?;
+        let bits = u64::from(byte & 0x7F);
+        // Tenth byte can only carry the most significant bit of u64.
+        if i == MAX_LEB128_U64_LEN - 1 && bits > 1 {
+            return Err(Error::ParseError);
+        }
+        result |= bits << (7 * i);
+        if byte & 0x80 == 0 {
+            return Ok(result);
+        }
+    }
+    Err(Error::ParseError)
+}
+
+/// Maximum number of 7 bit groups in a MIDI style variable-length quantity.
+const MAX_VLQ_GROUPS: u32 = 4;
+
+/// Reads MIDI style variable-length quantity with most significant group
+/// first.
+///
+/// Returns the value read and the number of bits it was encoded in.
+pub(crate) fn read_vlq_u32<'a, R>(input: &mut R) -> Result<(u32, u32), Error>
+where
+    R: ReaderExt<'a> + ?Sized,
+{
+    let mut result = 0u32;
+    for i in 1..=MAX_VLQ_GROUPS {
+        let byte = input.read_u8()?;
+        result = (result << 7) | u32::from(byte & 0x7F);
+        if byte & 0x80 == 0 {
+            return Ok((result, 7 * i));
+        }
+    }
+    Err(Error::ParseError)
+}
+
+/// Reads signed LEB128 encoded `i64`.
+///
+/// Redundant trailing sign extension groups are accepted as long as the
+/// encoding fits in ten bytes.
+pub(crate) fn read_sleb128_i64<'a, R>(input: &mut R) -> Result<i64, Error>
+where
+    R: ReaderExt<'a> + ?Sized,
+{
+    let mut result = 0i64;
+    for i in 0..MAX_LEB128_U64_LEN {
+        let byte = input.read_u8()?;
+        let bits = i64::from(byte & 0x7F);
+        // Tenth byte carries the most significant bit of i64 and the rest of
+        // its bits must be the sign extension of it.
+        if i == MAX_LEB128_U64_LEN - 1 && (byte & 0x80 != 0 || (bits != 0 && bits != 0x7F)) {
+            return Err(Error::ParseError);
+        }
+        let shift = 7 * i;
+        result |= bits << shift;
+        if byte & 0x80 == 0 {
+            if shift + 7 < 64 && byte & 0x40 != 0 {
+                result |= -1 << (shift + 7);
+            }
+            return Ok(result);
+        }
+    }
+    Err(Error::ParseError)
+}
+
+/// Reads zigzag encoded `i64` stored as unsigned LEB128.
+pub(crate) fn read_zigzag_i64<'a, R>(input: &mut R) -> Result<i64, Error>
+where
+    R: ReaderExt<'a> + ?Sized,
+{
+    read_uleb128_u64(input).map(zigzag_decode_u64)
+}
+
+/// Decodes zigzag encoded `u64` into `i64`.
+pub(crate) fn zigzag_decode_u64(value: u64) -> i64 {
+    ((value >> 1) as i64) ^ -((value & 1) as i64)
+}
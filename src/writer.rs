@@ -0,0 +1,439 @@
+//! Writing values in the formats `ReaderExt` reads them.
+//!
+//! A parser and a serializer of the same format can use the same crate:
+//! every `write_*` method of [WriterExt] produces bytes which the
+//! corresponding `read_*` method of [ReaderExt](crate::ReaderExt) reads
+//! back.
+//!
+//! Example:
+//!
+//! ```rust
+//! use untrusted::Input;
+//! use untrustended::writer::{Writer, WriterExt};
+//! use untrustended::{Error, ReaderExt};
+//!
+//! let mut writer = Writer::new();
+//! writer.write_u8(1);
+//! writer.write_u16be(0x0203);
+//! writer.write_u32le(0x0706_0504);
+//! assert_eq!(writer.as_slice(), [1, 2, 3, 4, 5, 6, 7]);
+//!
+//! let values = Input::from(writer.as_slice())
+//!     .read_all(Error::UnknownError, |input| {
+//!         Ok((input.read_u8()?, input.read_u16be()?, input.read_u32le()?))
+//!     })
+//!     .expect("read_all to succeed");
+//! assert_eq!(values, (1, 0x0203, 0x0706_0504));
+//! ```
+
+use alloc::vec::Vec;
+#[cfg(feature = "use_std")]
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+/// A growable buffer values are written into.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Writer {
+    buf: Vec<u8>,
+}
+
+impl Writer {
+    /// Construct a new empty Writer.
+    #[inline]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Construct a new empty Writer with room for `capacity` bytes.
+    #[inline]
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            buf: Vec::with_capacity(capacity),
+        }
+    }
+
+    /// Returns the number of bytes written.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.buf.len()
+    }
+
+    /// Returns `true` if nothing has been written, and `false` otherwise.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.buf.is_empty()
+    }
+
+    /// Returns the bytes written.
+    #[inline]
+    pub fn as_slice(&self) -> &[u8] {
+        &self.buf
+    }
+
+    /// Consumes the Writer and returns the bytes written.
+    #[inline]
+    pub fn into_vec(self) -> Vec<u8> {
+        self.buf
+    }
+}
+
+impl From<Writer> for Vec<u8> {
+    #[inline]
+    fn from(writer: Writer) -> Self {
+        writer.into_vec()
+    }
+}
+
+/// A trait to abstract the idea of writing bytes of a type into `Writer`.
+///
+/// This is the counterpart of [FromReader](crate::FromReader).
+pub trait ToWriter {
+    /// Write the bytes of a value in Big Endian byte order.
+    fn write_be(&self, _: &mut Writer);
+
+    /// Write the bytes of a value in Little Endian byte order.
+    fn write_le(&self, _: &mut Writer);
+}
+
+macro_rules! write_number {
+    ($type:ty) => {
+        impl ToWriter for $type {
+            #[inline]
+            fn write_be(&self, writer: &mut Writer) {
+                writer.write_bytes(&self.to_be_bytes());
+            }
+
+            #[inline]
+            fn write_le(&self, writer: &mut Writer) {
+                writer.write_bytes(&self.to_le_bytes());
+            }
+        }
+    };
+}
+
+write_number!(u8);
+write_number!(u16);
+write_number!(u32);
+write_number!(u64);
+write_number!(u128);
+write_number!(i8);
+write_number!(i16);
+write_number!(i32);
+write_number!(i64);
+write_number!(i128);
+write_number!(f32);
+write_number!(f64);
+
+#[cfg(feature = "use_std")]
+impl ToWriter for Ipv4Addr {
+    #[inline]
+    fn write_be(&self, writer: &mut Writer) {
+        u32::from(*self).write_be(writer);
+    }
+
+    #[inline]
+    fn write_le(&self, writer: &mut Writer) {
+        u32::from(*self).write_le(writer);
+    }
+}
+
+#[cfg(feature = "use_std")]
+impl ToWriter for Ipv6Addr {
+    #[inline]
+    fn write_be(&self, writer: &mut Writer) {
+        u128::from(*self).write_be(writer);
+    }
+
+    #[inline]
+    fn write_le(&self, writer: &mut Writer) {
+        u128::from(*self).write_le(writer);
+    }
+}
+
+/// The methods of this trait mirror the integer, floating point, LEB128,
+/// variable-length quantity and IP address methods of
+/// [ReaderExt](crate::ReaderExt).
+pub trait WriterExt {
+    /// Writes bytes as they are.
+    fn write_bytes(&mut self, bytes: &[u8]);
+
+    /// Writes a value in Big Endian byte order.
+    fn write_be<T: ToWriter>(&mut self, value: T);
+
+    /// Writes a value in Little Endian byte order.
+    fn write_le<T: ToWriter>(&mut self, value: T);
+
+    /// Writes 8 bit unsigned integer.
+    #[inline]
+    fn write_u8(&mut self, value: u8) {
+        self.write_be(value)
+    }
+
+    /// Writes 16 bit unsigned integer in big endian.
+    #[inline]
+    fn write_u16be(&mut self, value: u16) {
+        self.write_be(value)
+    }
+
+    /// Writes 32 bit unsigned integer in big endian.
+    #[inline]
+    fn write_u32be(&mut self, value: u32) {
+        self.write_be(value)
+    }
+
+    /// Writes 64 bit unsigned integer in big endian.
+    #[inline]
+    fn write_u64be(&mut self, value: u64) {
+        self.write_be(value)
+    }
+
+    /// Writes 128 bit unsigned integer in big endian.
+    #[inline]
+    fn write_u128be(&mut self, value: u128) {
+        self.write_be(value)
+    }
+
+    /// Writes 16 bit unsigned integer in little endian.
+    #[inline]
+    fn write_u16le(&mut self, value: u16) {
+        self.write_le(value)
+    }
+
+    /// Writes 32 bit unsigned integer in little endian.
+    #[inline]
+    fn write_u32le(&mut self, value: u32) {
+        self.write_le(value)
+    }
+
+    /// Writes 64 bit unsigned integer in little endian.
+    #[inline]
+    fn write_u64le(&mut self, value: u64) {
+        self.write_le(value)
+    }
+
+    /// Writes 128 bit unsigned integer in little endian.
+    #[inline]
+    fn write_u128le(&mut self, value: u128) {
+        self.write_le(value)
+    }
+
+    /// Writes the low 24 bits of an unsigned integer in big endian.
+    #[inline]
+    fn write_u24be(&mut self, value: u32) {
+        self.write_bytes(&value.to_be_bytes()[1..])
+    }
+
+    /// Writes the low 48 bits of an unsigned integer in big endian.
+    #[inline]
+    fn write_u48be(&mut self, value: u64) {
+        self.write_bytes(&value.to_be_bytes()[2..])
+    }
+
+    /// Writes the low 24 bits of an unsigned integer in little endian.
+    #[inline]
+    fn write_u24le(&mut self, value: u32) {
+        self.write_bytes(&value.to_le_bytes()[..3])
+    }
+
+    /// Writes the low 48 bits of an unsigned integer in little endian.
+    #[inline]
+    fn write_u48le(&mut self, value: u64) {
+        self.write_bytes(&value.to_le_bytes()[..6])
+    }
+
+    /// Writes 8 bit signed integer.
+    #[inline]
+    fn write_i8(&mut self, value: i8) {
+        self.write_be(value)
+    }
+
+    /// Writes 16 bit signed integer in big endian.
+    #[inline]
+    fn write_i16be(&mut self, value: i16) {
+        self.write_be(value)
+    }
+
+    /// Writes 32 bit signed integer in big endian.
+    #[inline]
+    fn write_i32be(&mut self, value: i32) {
+        self.write_be(value)
+    }
+
+    /// Writes 64 bit signed integer in big endian.
+    #[inline]
+    fn write_i64be(&mut self, value: i64) {
+        self.write_be(value)
+    }
+
+    /// Writes 128 bit signed integer in big endian.
+    #[inline]
+    fn write_i128be(&mut self, value: i128) {
+        self.write_be(value)
+    }
+
+    /// Writes 16 bit signed integer in little endian.
+    #[inline]
+    fn write_i16le(&mut self, value: i16) {
+        self.write_le(value)
+    }
+
+    /// Writes 32 bit signed integer in little endian.
+    #[inline]
+    fn write_i32le(&mut self, value: i32) {
+        self.write_le(value)
+    }
+
+    /// Writes 64 bit signed integer in little endian.
+    #[inline]
+    fn write_i64le(&mut self, value: i64) {
+        self.write_le(value)
+    }
+
+    /// Writes 128 bit signed integer in little endian.
+    #[inline]
+    fn write_i128le(&mut self, value: i128) {
+        self.write_le(value)
+    }
+
+    /// Writes the low 24 bits of a signed integer in big endian.
+    #[inline]
+    fn write_i24be(&mut self, value: i32) {
+        self.write_bytes(&value.to_be_bytes()[1..])
+    }
+
+    /// Writes the low 48 bits of a signed integer in big endian.
+    #[inline]
+    fn write_i48be(&mut self, value: i64) {
+        self.write_bytes(&value.to_be_bytes()[2..])
+    }
+
+    /// Writes the low 24 bits of a signed integer in little endian.
+    #[inline]
+    fn write_i24le(&mut self, value: i32) {
+        self.write_bytes(&value.to_le_bytes()[..3])
+    }
+
+    /// Writes the low 48 bits of a signed integer in little endian.
+    #[inline]
+    fn write_i48le(&mut self, value: i64) {
+        self.write_bytes(&value.to_le_bytes()[..6])
+    }
+
+    /// Writes 32 bit IEEE 754 floating point number in big endian.
+    #[inline]
+    fn write_f32be(&mut self, value: f32) {
+        self.write_be(value)
+    }
+
+    /// Writes 32 bit IEEE 754 floating point number in little endian.
+    #[inline]
+    fn write_f32le(&mut self, value: f32) {
+        self.write_le(value)
+    }
+
+    /// Writes 64 bit IEEE 754 floating point number in big endian.
+    #[inline]
+    fn write_f64be(&mut self, value: f64) {
+        self.write_be(value)
+    }
+
+    /// Writes 64 bit IEEE 754 floating point number in little endian.
+    #[inline]
+    fn write_f64le(&mut self, value: f64) {
+        self.write_le(value)
+    }
+
+    /// Writes unsigned LEB128 encoded 64 bit integer in the shortest
+    /// encoding.
+    fn write_uleb128(&mut self, mut value: u64) {
+        loop {
+            let byte = (value & 0x7F) as u8;
+            value >>= 7;
+            if value == 0 {
+                self.write_u8(byte);
+                return;
+            }
+            self.write_u8(byte | 0x80);
+        }
+    }
+
+    /// Writes signed LEB128 encoded 64 bit integer in the shortest encoding.
+    fn write_sleb128(&mut self, mut value: i64) {
+        loop {
+            let byte = (value & 0x7F) as u8;
+            value >>= 7;
+            let sign_bit = byte & 0x40 != 0;
+            if (value == 0 && !sign_bit) || (value == -1 && sign_bit) {
+                self.write_u8(byte);
+                return;
+            }
+            self.write_u8(byte | 0x80);
+        }
+    }
+
+    /// Writes the low 28 bits of an unsigned integer as MIDI style
+    /// variable-length quantity in the shortest encoding.
+    fn write_vlq_u32(&mut self, value: u32) {
+        let value = value & 0x0FFF_FFFF;
+        let mut groups = 1;
+        while groups < 4 && value >> (7 * groups) != 0 {
+            groups += 1;
+        }
+        write_vlq_groups(self, value, groups);
+    }
+
+    /// Writes the low 28 bits of a signed integer as signed MIDI style
+    /// variable-length quantity in the shortest encoding, as read by
+    /// [ReaderExt::read_vlq_i32](crate::ReaderExt::read_vlq_i32).
+    fn write_vlq_i32(&mut self, value: i32) {
+        let mut groups = 1;
+        while groups < 4 {
+            let half = 1i32 << (7 * groups - 1);
+            if (-half..half).contains(&value) {
+                break;
+            }
+            groups += 1;
+        }
+        let mask = (1u32 << (7 * groups)) - 1;
+        write_vlq_groups(self, value as u32 & mask, groups);
+    }
+
+    /// Writes IPv4 address in big endian.
+    #[cfg(feature = "use_std")]
+    #[inline]
+    fn write_ipv4addr(&mut self, value: Ipv4Addr) {
+        self.write_be(value)
+    }
+
+    /// Writes IPv6 address in big endian.
+    #[cfg(feature = "use_std")]
+    #[inline]
+    fn write_ipv6addr(&mut self, value: Ipv6Addr) {
+        self.write_be(value)
+    }
+}
+
+/// Writes `groups` groups of 7 bits of `value`, most significant group
+/// first, with the high bit set on all but the last byte.
+fn write_vlq_groups<W: WriterExt + ?Sized>(writer: &mut W, value: u32, groups: u32) {
+    for i in (0..groups).rev() {
+        let byte = ((value >> (7 * i)) & 0x7F) as u8;
+        writer.write_u8(if i == 0 { byte } else { byte | 0x80 });
+    }
+}
+
+impl WriterExt for Writer {
+    #[inline]
+    fn write_bytes(&mut self, bytes: &[u8]) {
+        self.buf.extend_from_slice(bytes);
+    }
+
+    #[inline]
+    fn write_be<T: ToWriter>(&mut self, value: T) {
+        value.write_be(self)
+    }
+
+    #[inline]
+    fn write_le<T: ToWriter>(&mut self, value: T) {
+        value.write_le(self)
+    }
+}
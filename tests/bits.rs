@@ -0,0 +1,64 @@
+#![deny(warnings)]
+
+use untrusted::{Input, Reader};
+use untrustended::{BitReader, Error, ReaderExt};
+
+/// Start of an IPv4 header: version and IHL, DSCP and ECN, total length,
+/// identification, flags and fragment offset.
+const IPV4_HEADER: [u8; 8] = [0x45, 0x00, 0x00, 0x54, 0x1C, 0x46, 0x40, 0x00];
+
+#[test]
+fn read_bits_ipv4_version_and_ihl() {
+    let mut bits = BitReader::new(&IPV4_HEADER[..1]);
+    assert_eq!(bits.read_bits(4), Ok(4));
+    assert_eq!(bits.read_bits(4), Ok(5));
+    assert!(bits.at_end());
+}
+
+#[test]
+fn read_bits_ipv4_flags_and_fragment_offset() {
+    let mut reader = Reader::new(Input::from(&IPV4_HEADER));
+    assert_eq!(reader.skip_bytes(6), Ok(()));
+    let mut bits = BitReader::from_reader(&mut reader, 2).expect("from_reader");
+    assert!(reader.at_end());
+
+    // Don't Fragment is set.
+    assert_eq!(bits.read_bits(3), Ok(0b010));
+    assert_eq!(bits.read_bits(13), Ok(0));
+
+    // More Fragments is set, fragment offset 0x1ABC.
+    let mut bits = BitReader::new(&[0x3A, 0xBC]);
+    assert_eq!(bits.read_bits(3), Ok(0b001));
+    assert_eq!(bits.read_bits(13), Ok(0x1ABC));
+}
+
+#[test]
+fn read_bits_across_bytes() {
+    let buf = [0xFF, 0x00, 0xAA, 0x55, 0x01, 0x23, 0x45, 0x67, 0x89, 0xAB];
+    let mut bits = BitReader::from(Input::from(&buf));
+    assert_eq!(bits.read_bits(0), Ok(0));
+    assert_eq!(bits.read_bits(4), Ok(0xF));
+    assert_eq!(bits.read_bits(8), Ok(0xF0));
+    assert_eq!(bits.read_bits(64), Ok(0x0AA5_5012_3456_789A));
+    assert_eq!(bits.read_bits(5), Err(Error::EndOfInput));
+    assert_eq!(bits.read_bits(4), Ok(0xB));
+    assert!(bits.at_end());
+}
+
+#[test]
+fn read_bits_too_wide() {
+    let mut bits = BitReader::new(&[0u8; 16]);
+    assert_eq!(bits.read_bits(65), Err(Error::ParseError));
+}
+
+#[test]
+fn align() {
+    let buf = [0b1010_0000, 0x7F];
+    let mut bits = BitReader::new(&buf);
+    bits.align();
+    assert_eq!(bits.read_bits(3), Ok(0b101));
+    bits.align();
+    assert_eq!(bits.read_bits(8), Ok(0x7F));
+    bits.align();
+    assert!(bits.at_end());
+}
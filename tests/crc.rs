@@ -0,0 +1,13 @@
+#![deny(warnings)]
+
+use untrustended::crc;
+
+#[test]
+fn crc16_ccitt_check_value() {
+    assert_eq!(crc::crc16_ccitt(b"123456789"), 0x29B1);
+}
+
+#[test]
+fn crc16_ccitt_empty() {
+    assert_eq!(crc::crc16_ccitt(b""), 0xFFFF);
+}
@@ -0,0 +1,80 @@
+#![deny(warnings)]
+
+use untrusted::{Input, Reader};
+use untrustended::{Error, Readable};
+
+#[derive(Debug, PartialEq, Readable)]
+struct Header {
+    version: u8,
+    flags: u16,
+    length: u32,
+}
+
+#[derive(Debug, PartialEq, Readable)]
+#[untrustended(le)]
+struct Mixed {
+    flags: u16,
+    #[untrustended(be)]
+    length: u32,
+}
+
+#[derive(Debug, PartialEq, Readable)]
+struct Pair(u8, #[untrustended(le)] u16);
+
+const BUF: [u8; 7] = [0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07];
+
+#[test]
+fn derive_readable() {
+    let header = Input::from(&BUF).read_all(Error::UnknownError, Header::read);
+    assert_eq!(
+        header,
+        Ok(Header {
+            version: 0x01,
+            flags: 0x0203,
+            length: 0x0405_0607,
+        })
+    );
+
+    let header = Input::from(&BUF).read_all(Error::UnknownError, Header::read_le);
+    assert_eq!(
+        header,
+        Ok(Header {
+            version: 0x01,
+            flags: 0x0302,
+            length: 0x0706_0504,
+        })
+    );
+
+    let mut reader = Reader::new(Input::from(&BUF[..6]));
+    assert_eq!(Header::read(&mut reader), Err(Error::EndOfInput));
+}
+
+#[test]
+fn derive_readable_attributes() {
+    let mixed = Input::from(&BUF[..6]).read_all(Error::UnknownError, Mixed::read);
+    assert_eq!(
+        mixed,
+        Ok(Mixed {
+            flags: 0x0201,
+            length: 0x0304_0506,
+        })
+    );
+
+    let mixed = Input::from(&BUF[..6]).read_all(Error::UnknownError, Mixed::read_be);
+    assert_eq!(
+        mixed,
+        Ok(Mixed {
+            flags: 0x0102,
+            length: 0x0304_0506,
+        })
+    );
+
+    let pair = Input::from(&BUF[..3]).read_all(Error::UnknownError, Pair::read);
+    assert_eq!(pair, Ok(Pair(0x01, 0x0302)));
+}
+
+#[test]
+fn derive_readable_compile_errors() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
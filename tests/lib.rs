@@ -209,12 +209,84 @@ quickcheck! {
         ys == reader.read_i128le().expect("read_i128le")
     }
 
+    fn prop_read_f32be(xs: f32) -> bool {
+        let mut buf = Vec::new();
+        buf.write_f32::<BigEndian>(xs).expect("write_f32");
+        let mut reader = reader(&buf);
+        xs.to_bits() == reader.read_f32be().expect("read_f32be").to_bits()
+    }
+
+    fn prop_read_f32le(xs: f32) -> bool {
+        let mut buf = Vec::new();
+        buf.write_f32::<LittleEndian>(xs).expect("write_f32");
+        let mut reader = reader(&buf);
+        xs.to_bits() == reader.read_f32le().expect("read_f32le").to_bits()
+    }
+
+    fn prop_read_f64be(xs: f64) -> bool {
+        let mut buf = Vec::new();
+        buf.write_f64::<BigEndian>(xs).expect("write_f64");
+        let mut reader = reader(&buf);
+        xs.to_bits() == reader.read_f64be().expect("read_f64be").to_bits()
+    }
+
+    fn prop_read_f64le(xs: f64) -> bool {
+        let mut buf = Vec::new();
+        buf.write_f64::<LittleEndian>(xs).expect("write_f64");
+        let mut reader = reader(&buf);
+        xs.to_bits() == reader.read_f64le().expect("read_f64le").to_bits()
+    }
+
+    fn prop_read_f32_special(bits: u32) -> bool {
+        // NaN with arbitrary payload and an arbitrary subnormal.
+        let specials = [
+            f32::NAN,
+            f32::from_bits(bits | 0x7F80_0001),
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::from_bits(bits & 0x807F_FFFF),
+        ];
+        let mut buf = Vec::new();
+        for x in specials {
+            buf.write_f32::<BigEndian>(x).expect("write_f32");
+            buf.write_f32::<LittleEndian>(x).expect("write_f32");
+        }
+        let mut reader = reader(&buf);
+        specials.iter().all(|x| {
+            let be = reader.read_be::<f32>().expect("read_be");
+            let le = reader.read_f32le().expect("read_f32le");
+            x.to_bits() == be.to_bits() && x.to_bits() == le.to_bits()
+        })
+    }
+
+    fn prop_read_f64_special(bits: u64) -> bool {
+        // NaN with arbitrary payload and an arbitrary subnormal.
+        let specials = [
+            f64::NAN,
+            f64::from_bits(bits | 0x7FF0_0000_0000_0001),
+            f64::INFINITY,
+            f64::NEG_INFINITY,
+            f64::from_bits(bits & 0x800F_FFFF_FFFF_FFFF),
+        ];
+        let mut buf = Vec::new();
+        for x in specials {
+            buf.write_f64::<BigEndian>(x).expect("write_f64");
+            buf.write_f64::<LittleEndian>(x).expect("write_f64");
+        }
+        let mut reader = reader(&buf);
+        specials.iter().all(|x| {
+            let be = reader.read_f64be().expect("read_f64be");
+            let le = reader.read_le::<f64>().expect("read_le");
+            x.to_bits() == be.to_bits() && x.to_bits() == le.to_bits()
+        })
+    }
+
     fn prop_read_bytes_less_safe(xs: Vec<u8>) -> bool {
         let mut reader = reader(&xs);
         xs == reader.read_bytes_less_safe(xs.len()).expect("read_bytes_less_safes")
     }
 
-    #[cfg(feature = "use_std")]
+    #[cfg(feature = "alloc")]
     fn prop_read_utf8(xs: String) -> bool {
         use std::io::Write;
         let mut buf = Vec::new();
@@ -224,7 +296,7 @@ quickcheck! {
         xs == reader.read_utf8(len).expect("read_utf8")
     }
 
-    #[cfg(feature = "use_std")]
+    #[cfg(feature = "alloc")]
     fn prop_read_utf16(xs: String) -> bool {
         let mut buf = Vec::new();
         for short in xs.encode_utf16() {
@@ -234,6 +306,117 @@ quickcheck! {
         let mut reader = reader(&buf);
         xs == reader.read_utf16(len).expect("read_utf16")
     }
+
+    #[cfg(feature = "alloc")]
+    fn prop_read_utf16le(xs: String) -> bool {
+        let mut buf = Vec::new();
+        for short in xs.encode_utf16() {
+            buf.write_u16::<LittleEndian>(short).expect("write_u16");
+        }
+        let len = buf.len();
+        let mut reader = reader(&buf);
+        xs == reader.read_utf16le(len).expect("read_utf16le")
+    }
+
+    fn prop_read_sleb128(xs: i64) -> bool {
+        let buf = encode_sleb128(xs);
+        let mut reader = reader(&buf);
+        xs == reader.read_sleb128().expect("read_sleb128") && reader.at_end()
+    }
+}
+
+/// Reference signed LEB128 encoder.
+fn encode_sleb128(mut value: i64) -> Vec<u8> {
+    let mut buf = Vec::new();
+    loop {
+        let byte = (value & 0x7F) as u8;
+        value >>= 7;
+        let done = (value == 0 && byte & 0x40 == 0) || (value == -1 && byte & 0x40 != 0);
+        if done {
+            buf.push(byte);
+            return buf;
+        }
+        buf.push(byte | 0x80);
+    }
+}
+
+#[test]
+fn read_sleb128_specials() {
+    let specials = vec![i64::MIN, i64::MIN + 1, -65, -64, -1, 0, 63, 64, i64::MAX];
+    for s in specials {
+        let buf = encode_sleb128(s);
+        let mut reader = reader(&buf);
+        assert_eq!(s, reader.read_sleb128().expect("read_sleb128"));
+        assert!(reader.at_end());
+    }
+}
+
+#[test]
+fn read_sleb128_invalid() {
+    let mut truncated = reader(&[0xFF, 0xFF]);
+    assert_eq!(
+        truncated.read_sleb128(),
+        Err(untrustended::Error::EndOfInput)
+    );
+    // Tenth byte isn't a sign extension of bit 63.
+    let buf = [0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x01];
+    let mut overflow = reader(&buf);
+    assert_eq!(
+        overflow.read_sleb128(),
+        Err(untrustended::Error::ParseError)
+    );
+}
+
+// Implementing `ReaderExt` outside the crate needs only the methods it had
+// before the plumbing of other readers was added.
+struct ForeignReader<'a>(Reader<'a>);
+
+impl<'a> ReaderExt<'a> for ForeignReader<'a> {
+    fn read_byte(&mut self) -> Result<u8, untrusted::EndOfInput> {
+        self.0.read_byte()
+    }
+
+    fn read_bytes(&mut self, num_bytes: usize) -> Result<Input<'a>, untrusted::EndOfInput> {
+        self.0.read_bytes(num_bytes)
+    }
+
+    fn read_be<T: untrustended::FromReader>(&mut self) -> Result<T, untrustended::Error> {
+        T::read_be(&mut self.0)
+    }
+
+    fn read_le<T: untrustended::FromReader>(&mut self) -> Result<T, untrustended::Error> {
+        T::read_le(&mut self.0)
+    }
+}
+
+#[test]
+fn foreign_reader_ext() {
+    let buf = [0x01, 0x02, 0x03, 0x04, 0x05];
+    let mut foreign = ForeignReader(reader(&buf));
+    assert_eq!(foreign.read_u8(), Ok(0x01));
+    assert_eq!(foreign.read_u16be(), Ok(0x0203));
+    assert_eq!(foreign.read_u16le(), Ok(0x0504));
+    assert_eq!(foreign.read_u8(), Err(untrustended::Error::EndOfInput));
+}
+
+#[test]
+fn read_bool() {
+    let buf = [0x00, 0x01, 0x02];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_bool(), Ok(false));
+    assert_eq!(reader.read_bool(), Ok(true));
+    assert_eq!(reader.read_bool(), Err(untrustended::Error::InvalidValue));
+    assert_eq!(reader.read_bool(), Err(untrustended::Error::EndOfInput));
+}
+
+#[test]
+fn read_bool_lenient() {
+    let buf = [0x00, 0x01, 0x02, 0xFF];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_bool_lenient(), Ok(false));
+    assert_eq!(reader.read_bool_lenient(), Ok(true));
+    assert_eq!(reader.read_bool_lenient(), Ok(true));
+    assert_eq!(reader.read_bool_lenient(), Ok(true));
 }
 
 #[test]
@@ -269,6 +452,40 @@ fn read_i24be_specials() {
     }
 }
 
+#[test]
+fn read_i32be_from_i24() {
+    let valid = [
+        ([0x00, 0x00, 0x00, 0x00], 0),
+        ([0x00, 0x7F, 0xFF, 0xFF], 8_388_607),
+        ([0xFF, 0x80, 0x00, 0x00], -8_388_608),
+        ([0xFF, 0xFF, 0xFF, 0xFF], -1),
+    ];
+    for (buf, value) in valid {
+        let mut reader = reader(&buf);
+        assert_eq!(reader.read_i32be_from_i24(), Ok(value));
+    }
+
+    let invalid = [
+        [0x00, 0x80, 0x00, 0x00], // 2^23 doesn't fit.
+        [0xFF, 0x7F, 0xFF, 0xFF], // -2^23 - 1 doesn't fit.
+        [0x01, 0x00, 0x00, 0x00],
+        [0x80, 0x00, 0x00, 0x00],
+    ];
+    for buf in invalid {
+        let mut reader = reader(&buf);
+        assert_eq!(
+            reader.read_i32be_from_i24(),
+            Err(untrustended::Error::InvalidValue)
+        );
+    }
+
+    let mut short = reader(&[0x00, 0x00, 0x01]);
+    assert_eq!(
+        short.read_i32be_from_i24(),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
 #[test]
 fn read_i32be_specials() {
     let specials = vec![i32::MIN, i32::MIN + 1, -1, 0, 1, i32::MAX - 1, i32::MAX];
@@ -385,7 +602,7 @@ fn read_i64le_specials() {
 }
 
 #[test]
-#[cfg(feature = "use_std")]
+#[cfg(feature = "alloc")]
 fn read_utf16_with_odd_length() {
     let mut reader = reader(&[]);
     match reader.read_utf16(3) {
@@ -394,6 +611,98 @@ fn read_utf16_with_odd_length() {
     }
 }
 
+#[test]
+#[cfg(feature = "alloc")]
+fn read_utf16le() {
+    // "h€" followed by an unpaired surrogate.
+    let buf = [0x68, 0x00, 0xAC, 0x20, 0x00, 0xD8, 0x00];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_utf16le(4), Ok(String::from("h€")));
+    assert_eq!(reader.read_utf16le(1), Err(untrustended::Error::ParseError));
+    assert_eq!(reader.read_utf16le(2), Err(untrustended::Error::ParseError));
+    assert_eq!(reader.read_utf16le(2), Err(untrustended::Error::EndOfInput));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_utf16_with_bom() {
+    let text = "h€\u{1F600}";
+    let mut be = vec![0xFE, 0xFF];
+    let mut le = vec![0xFF, 0xFE];
+    for short in text.encode_utf16() {
+        be.write_u16::<BigEndian>(short).expect("write_u16");
+        le.write_u16::<LittleEndian>(short).expect("write_u16");
+    }
+    for buf in [be, le] {
+        let mut reader = reader(&buf);
+        assert_eq!(
+            reader.read_utf16_with_bom(buf.len()),
+            Ok(String::from(text))
+        );
+        assert!(reader.at_end());
+    }
+
+    let mut no_bom = reader(&[0x00, 0x68, 0x00, 0x69]);
+    assert_eq!(
+        no_bom.read_utf16_with_bom(4),
+        Err(untrustended::Error::ParseError)
+    );
+
+    let mut too_short = reader(&[0xFE, 0xFF]);
+    assert_eq!(
+        too_short.read_utf16_with_bom(1),
+        Err(untrustended::Error::ParseError)
+    );
+
+    let mut truncated = reader(&[0xFF, 0xFE, 0x68]);
+    assert_eq!(
+        truncated.read_utf16_with_bom(4),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_text_with_bom() {
+    let text = "h€\u{1F600}";
+    let utf16: Vec<u16> = text.encode_utf16().collect();
+    let utf32: Vec<u32> = text.chars().map(u32::from).collect();
+
+    let mut encodings: Vec<Vec<u8>> = vec![
+        text.as_bytes().to_vec(),
+        [&[0xEF, 0xBB, 0xBF], text.as_bytes()].concat(),
+        vec![0xFE, 0xFF],
+        vec![0xFF, 0xFE],
+        vec![0x00, 0x00, 0xFE, 0xFF],
+        vec![0xFF, 0xFE, 0x00, 0x00],
+    ];
+    encodings[2].extend(utf16.iter().flat_map(|u| u.to_be_bytes()));
+    encodings[3].extend(utf16.iter().flat_map(|u| u.to_le_bytes()));
+    encodings[4].extend(utf32.iter().flat_map(|u| u.to_be_bytes()));
+    encodings[5].extend(utf32.iter().flat_map(|u| u.to_le_bytes()));
+
+    for buf in encodings {
+        let mut reader = reader(&buf);
+        assert_eq!(reader.read_text_with_bom(buf.len()).as_deref(), Ok(text));
+        assert!(reader.at_end());
+    }
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_text_with_bom_invalid() {
+    let mut utf16 = reader(&[0xFE, 0xFF, 0x00]);
+    assert_eq!(
+        utf16.read_text_with_bom(3),
+        Err(untrustended::Error::ParseError)
+    );
+    let mut utf32 = reader(&[0x00, 0x00, 0xFE, 0xFF, 0x00, 0x11, 0x00, 0x00]);
+    assert_eq!(
+        utf32.read_text_with_bom(8),
+        Err(untrustended::Error::ParseError)
+    );
+}
+
 #[test]
 #[cfg(feature = "use_std")]
 fn read_ipv4addr() {
@@ -435,3 +744,1821 @@ fn read_ipv6addr() {
         assert_eq!(addr, reader.read_ipv6addr().expect("read_ipv6addr"));
     }
 }
+
+#[test]
+#[cfg(feature = "use_std")]
+fn read_socketaddr_v4() {
+    use std::io::Write;
+    use std::net::SocketAddrV4;
+    let addrs: Vec<SocketAddrV4> = vec![
+        "0.0.0.0:0".parse().expect("parse socket addr"),
+        "192.0.2.1:53".parse().expect("parse socket addr"),
+        "203.0.113.255:65535".parse().expect("parse socket addr"),
+    ];
+    for addr in addrs {
+        let mut buf = Vec::new();
+        buf.write_all(&addr.ip().octets()).expect("write_all");
+        buf.write_u16::<BigEndian>(addr.port()).expect("write_u16");
+        let mut be = reader(&buf);
+        assert_eq!(addr, be.read_socketaddr_v4().expect("read_socketaddr_v4"));
+        assert!(be.at_end());
+
+        let mut buf = Vec::new();
+        buf.write_u32::<LittleEndian>(u32::from(*addr.ip()))
+            .expect("write_u32");
+        buf.write_u16::<LittleEndian>(addr.port())
+            .expect("write_u16");
+        let mut le = reader(&buf);
+        assert_eq!(Ok(addr), le.read_le::<SocketAddrV4>());
+    }
+
+    let mut short = reader(&[192, 0, 2, 1, 0]);
+    assert_eq!(
+        short.read_socketaddr_v4(),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+#[cfg(feature = "use_std")]
+fn read_socketaddr_v6() {
+    use std::io::Write;
+    use std::net::SocketAddrV6;
+    let addrs: Vec<SocketAddrV6> = vec![
+        "[2001:DB8::]:0".parse().expect("parse socket addr"),
+        "[2001:DB8:ff00:00ff:f00f:0ff0:0000:ffff]:443"
+            .parse()
+            .expect("parse socket addr"),
+        "[2001:DB8:ffff:ffff:ffff:ffff:ffff:ffff]:65535"
+            .parse()
+            .expect("parse socket addr"),
+    ];
+    for addr in addrs {
+        let mut buf = Vec::new();
+        buf.write_all(&addr.ip().octets()).expect("write_all");
+        buf.write_u16::<BigEndian>(addr.port()).expect("write_u16");
+        let mut be = reader(&buf);
+        assert_eq!(Ok(addr), be.read_be::<SocketAddrV6>());
+        assert!(be.at_end());
+        let mut again = reader(&buf);
+        assert_eq!(
+            addr,
+            again.read_socketaddr_v6().expect("read_socketaddr_v6")
+        );
+    }
+}
+
+#[test]
+fn read_mac_addr() {
+    // Destination MAC of an Ethernet frame followed by the source MAC.
+    let buf = [
+        0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E, 0x02, 0x00, 0x5E, 0x10, 0x00, 0x00, 0x00, 0x01,
+    ];
+    let mut reader = reader(&buf);
+    assert_eq!(
+        reader.read_mac_addr(),
+        Ok([0x00, 0x1A, 0x2B, 0x3C, 0x4D, 0x5E])
+    );
+    assert_eq!(
+        reader.read_mac_addr(),
+        Ok([0x02, 0x00, 0x5E, 0x10, 0x00, 0x00])
+    );
+    assert_eq!(reader.read_mac_addr(), Err(untrustended::Error::EndOfInput));
+}
+
+#[test]
+fn read_eui64() {
+    let buf = [0x02, 0x1A, 0x2B, 0xFF, 0xFE, 0x3C, 0x4D, 0x5E];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_eui64(), Ok(buf));
+    assert_eq!(reader.read_eui64(), Err(untrustended::Error::EndOfInput));
+}
+
+#[test]
+fn read_uuid() {
+    // 00112233-4455-6677-8899-aabbccddeeff
+    let uuid = [
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE,
+        0xFF,
+    ];
+    let mut wire = reader(&uuid);
+    assert_eq!(wire.read_uuid(), Ok(uuid));
+    assert_eq!(wire.read_uuid(), Err(untrustended::Error::EndOfInput));
+
+    let guid = [
+        0x33, 0x22, 0x11, 0x00, 0x55, 0x44, 0x77, 0x66, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE,
+        0xFF,
+    ];
+    let mut mixed = reader(&guid);
+    assert_eq!(mixed.read_uuid_mixed_endian(), Ok(uuid));
+    assert_eq!(
+        mixed.read_uuid_mixed_endian(),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_ipv6_segments_be() {
+    let buf = [
+        0x20, 0x01, 0x0D, 0xB8, 0xFF, 0x00, 0x00, 0xFF, 0xF0, 0x0F, 0x0F, 0xF0, 0x00, 0x00, 0xFF,
+        0xFF, 0x00,
+    ];
+    let mut reader = reader(&buf);
+    assert_eq!(
+        reader.read_ipv6_segments_be(),
+        Ok([0x2001, 0x0DB8, 0xFF00, 0x00FF, 0xF00F, 0x0FF0, 0x0000, 0xFFFF])
+    );
+    assert_eq!(
+        reader.read_ipv6_segments_be(),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+#[cfg(feature = "use_std")]
+fn read_ipv6_segments_be_matches_ipv6addr() {
+    use std::net::Ipv6Addr;
+    let addr: Ipv6Addr = "2001:DB8::8a2e:370:7334".parse().expect("parse ipv6 addr");
+    let octets = addr.octets();
+    let mut reader = reader(&octets);
+    assert_eq!(reader.read_ipv6_segments_be(), Ok(addr.segments()));
+}
+
+#[test]
+fn read_bytes_chunked() {
+    let buf: Vec<u8> = (1..=10).collect();
+    let mut reader = reader(&buf);
+    let mut sum = 0u32;
+    let mut lens = Vec::new();
+    reader
+        .read_bytes_chunked(9, 4, |chunk| {
+            sum += chunk.iter().map(|&b| u32::from(b)).sum::<u32>();
+            lens.push(chunk.len());
+            Ok(())
+        })
+        .expect("read_bytes_chunked");
+    assert_eq!(sum, 45);
+    assert_eq!(lens, vec![4, 4, 1]);
+    assert_eq!(reader.read_u8().expect("read_u8"), 10);
+}
+
+#[test]
+fn read_bytes_chunked_truncated() {
+    let buf: Vec<u8> = (1..=10).collect();
+    let mut reader = reader(&buf);
+    let res = reader.read_bytes_chunked(11, 4, |_| panic!("called on truncated input"));
+    assert_eq!(res, Err(untrustended::Error::EndOfInput));
+}
+
+#[test]
+fn read_array() {
+    let buf: Vec<u8> = (0..33).collect();
+    let mut reader = reader(&buf);
+    let empty: [u8; 0] = reader.read_array().expect("read_array");
+    assert_eq!(empty, []);
+    let hash: [u8; 32] = reader.read_array().expect("read_array");
+    assert_eq!(hash[..], buf[..32]);
+    assert_eq!(
+        reader.read_array::<2>(),
+        Err(untrustended::Error::EndOfInput)
+    );
+    assert_eq!(reader.read_array::<1>(), Ok([32]));
+}
+
+#[test]
+fn read_bytes_array() {
+    let buf = [0xDE, 0xAD, 0xBE, 0xEF, 0x01, 0x02];
+    let mut reader = reader(&buf);
+    let magic: &[u8; 4] = reader.read_bytes_array().expect("read_bytes_array");
+    assert_eq!(magic, &[0xDE, 0xAD, 0xBE, 0xEF]);
+    assert_eq!(
+        reader.read_bytes_array::<4>(),
+        Err(untrustended::Error::EndOfInput)
+    );
+    assert_eq!(reader.read_u8(), Ok(0x01));
+}
+
+#[test]
+fn read_i8_array() {
+    let buf = [0x00, 0x7F, 0x80, 0xFF, 0x01];
+    let mut reader = reader(&buf);
+    let arr: [i8; 4] = reader.read_i8_array().expect("read_i8_array");
+    assert_eq!(arr, [0, i8::MAX, i8::MIN, -1]);
+    assert_eq!(
+        reader.read_i8_array::<2>(),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_u64_array() {
+    // SHA-512 initial hash value.
+    let words: [u64; 8] = [
+        0x6a09e667f3bcc908,
+        0xbb67ae8584caa73b,
+        0x3c6ef372fe94f82b,
+        0xa54ff53a5f1d36f1,
+        0x510e527fade682d1,
+        0x9b05688c2b3e6c1f,
+        0x1f83d9abfb41bd6b,
+        0x5be0cd19137e2179,
+    ];
+    let mut buf = Vec::new();
+    for word in words {
+        buf.write_u64::<BigEndian>(word).expect("write_u64");
+    }
+    for word in words {
+        buf.write_u64::<LittleEndian>(word).expect("write_u64");
+    }
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_u64be_array::<8>(), Ok(words));
+    assert_eq!(reader.read_u64le_array::<8>(), Ok(words));
+    assert!(reader.at_end());
+}
+
+#[test]
+fn read_u64_array_truncated() {
+    let buf = [0u8; 15];
+    let mut reader = reader(&buf);
+    assert_eq!(
+        reader.read_u64be_array::<2>(),
+        Err(untrustended::Error::EndOfInput)
+    );
+    assert_eq!(reader.read_u64le_array::<1>(), Ok([0]));
+}
+
+#[test]
+fn read_map_bytes() {
+    // 12 bit width and 12 bit height packed into three bytes.
+    let buf = [0x12, 0x34, 0x56, 0xFF];
+    let mut reader = reader(&buf);
+    let size = reader.read_map_bytes(|[a, b, c]: [u8; 3]| {
+        let width = (u16::from(a) << 4) | u16::from(b >> 4);
+        let height = (u16::from(b & 0x0F) << 8) | u16::from(c);
+        Ok((width, height))
+    });
+    assert_eq!(size, Ok((0x123, 0x456)));
+    assert_eq!(
+        reader.read_map_bytes(|[b]: [u8; 1]| if b == 0 {
+            Ok(())
+        } else {
+            Err(untrustended::Error::InvalidValue)
+        }),
+        Err(untrustended::Error::InvalidValue)
+    );
+    assert_eq!(
+        reader.read_map_bytes(|_: [u8; 1]| Ok(())),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_flag_byte() {
+    let buf = [0b1010_0001];
+    let mut reader = reader(&buf);
+    let flags = reader.read_flag_byte().expect("read_flag_byte");
+    assert_eq!(flags, untrustended::FlagByte(0b1010_0001));
+    assert!(flags.get(0));
+    assert!(!flags.get(1));
+    assert!(flags.get(2));
+    assert!(flags.get(7));
+    assert!(!flags.get(8));
+    assert!(!flags.get(u32::MAX));
+    assert_eq!(
+        reader.read_flag_byte(),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_colors() {
+    let buf = [
+        0x10, 0x20, 0x30, 0x10, 0x20, 0x30, 0x40, 0xF8, 0x00, 0xE0, 0x07, 0x00, 0x1F, 0xFF, 0xFF,
+        0x84, 0x10, 0x00,
+    ];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_rgb(), Ok([0x10, 0x20, 0x30]));
+    assert_eq!(reader.read_rgba(), Ok([0x10, 0x20, 0x30, 0x40]));
+    assert_eq!(reader.read_rgb565be(), Ok([255, 0, 0]));
+    assert_eq!(reader.read_rgb565le(), Ok([0, 255, 0]));
+    assert_eq!(reader.read_rgb565be(), Ok([0, 0, 255]));
+    assert_eq!(reader.read_rgb565be(), Ok([255, 255, 255]));
+    assert_eq!(reader.read_rgb565be(), Ok([132, 130, 132]));
+    assert_eq!(reader.read_rgb(), Err(untrustended::Error::EndOfInput));
+}
+
+#[test]
+fn read_geo_e7_be() {
+    let mut buf = Vec::new();
+    buf.write_i32::<BigEndian>(601_700_000).unwrap();
+    buf.write_i32::<BigEndian>(249_384_000).unwrap();
+    buf.write_i32::<BigEndian>(-900_000_000).unwrap();
+    buf.write_i32::<BigEndian>(1_800_000_000).unwrap();
+    buf.write_i32::<BigEndian>(900_000_001).unwrap();
+    buf.write_i32::<BigEndian>(0).unwrap();
+    buf.write_i32::<BigEndian>(0).unwrap();
+    buf.write_i32::<BigEndian>(-1_800_000_001).unwrap();
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_geo_e7_be(), Ok((60.17, 24.9384)));
+    assert_eq!(reader.read_geo_e7_be(), Ok((-90.0, 180.0)));
+    assert_eq!(
+        reader.read_geo_e7_be(),
+        Err(untrustended::Error::InvalidValue)
+    );
+    assert_eq!(
+        reader.read_geo_e7_be(),
+        Err(untrustended::Error::InvalidValue)
+    );
+}
+
+#[test]
+fn read_fixed_16_16_rounded() {
+    use untrustended::RoundingMode::{Nearest, Truncate};
+    let buf = [
+        0x00, 0x01, 0x80, 0x00, 0xFF, 0xFE, 0x80, 0x00, 0x7F, 0xFF, 0xFF, 0xFF, 0x7F, 0xFF, 0xFF,
+        0xFF, 0x80, 0x00, 0x00, 0x03, 0x80, 0x00, 0x00, 0x03,
+    ];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_fixed_16_16_rounded(Nearest), Ok(1.5));
+    assert_eq!(reader.read_fixed_16_16_rounded(Truncate), Ok(-1.5));
+    // 0x7FFFFFFF has 31 significant bits, so it's between two f32s.
+    assert_eq!(reader.read_fixed_16_16_rounded(Nearest), Ok(32768.0));
+    assert_eq!(
+        reader.read_fixed_16_16_rounded(Truncate),
+        Ok(32768.0 - 1.0 / 512.0)
+    );
+    assert_eq!(reader.read_fixed_16_16_rounded(Nearest), Ok(-32768.0));
+    assert_eq!(
+        reader.read_fixed_16_16_rounded(Truncate),
+        Ok(-32768.0 + 1.0 / 512.0)
+    );
+    assert_eq!(
+        reader.read_fixed_16_16_rounded(Nearest),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_points() {
+    let mut buf = Vec::new();
+    buf.write_f32::<BigEndian>(1.5).unwrap();
+    buf.write_f32::<BigEndian>(-2.0).unwrap();
+    buf.write_f32::<LittleEndian>(0.25).unwrap();
+    buf.write_f32::<LittleEndian>(8.0).unwrap();
+    buf.write_f64::<BigEndian>(-1.0e10).unwrap();
+    buf.write_f64::<BigEndian>(3.0).unwrap();
+    buf.write_f64::<LittleEndian>(0.5).unwrap();
+    buf.write_f64::<LittleEndian>(-0.125).unwrap();
+    buf.write_f32::<BigEndian>(1.0).unwrap();
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_point_f32be(), Ok((1.5, -2.0)));
+    assert_eq!(reader.read_point_f32le(), Ok((0.25, 8.0)));
+    assert_eq!(reader.read_point_f64be(), Ok((-1.0e10, 3.0)));
+    assert_eq!(reader.read_point_f64le(), Ok((0.5, -0.125)));
+    assert_eq!(
+        reader.read_point_f32be(),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_pcm_s16le_normalized() {
+    let mut buf = Vec::new();
+    for sample in [i16::MIN, 16384, 0, i16::MAX] {
+        buf.write_i16::<LittleEndian>(sample).unwrap();
+    }
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_pcm_s16le_normalized(), Ok(-1.0));
+    assert_eq!(reader.read_pcm_s16le_normalized(), Ok(0.5));
+    assert_eq!(reader.read_pcm_s16le_normalized(), Ok(0.0));
+    assert_eq!(reader.read_pcm_s16le_normalized(), Ok(32767.0 / 32768.0));
+    assert_eq!(
+        reader.read_pcm_s16le_normalized(),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_pcm_s24le() {
+    let buf = [0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x80, 0xFF, 0xFF, 0x7F, 0x00];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_pcm_s24le(), Ok(-1));
+    assert_eq!(reader.read_pcm_s24le(), Ok(-8_388_608));
+    assert_eq!(reader.read_pcm_s24le(), Ok(8_388_607));
+    assert_eq!(
+        reader.read_pcm_s24le(),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_pcm_s24le_normalized() {
+    let buf = [0x00, 0x00, 0x80, 0x00, 0x00, 0x40, 0xFF, 0xFF, 0xFF];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_pcm_s24le_normalized(), Ok(-1.0));
+    assert_eq!(reader.read_pcm_s24le_normalized(), Ok(0.5));
+    assert_eq!(reader.read_pcm_s24le_normalized(), Ok(-1.0 / 8_388_608.0));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_pcm_s16le_normalized_vec() {
+    let mut buf = Vec::new();
+    for sample in [i16::MIN, -8192, 16384] {
+        buf.write_i16::<LittleEndian>(sample).unwrap();
+    }
+    let mut reader = reader(&buf);
+    assert_eq!(
+        reader.read_pcm_s16le_normalized_vec(4),
+        Err(untrustended::Error::EndOfInput)
+    );
+    assert_eq!(
+        reader.read_pcm_s16le_normalized_vec(3),
+        Ok(vec![-1.0, -0.25, 0.5])
+    );
+}
+
+#[test]
+fn read_bcd_ordered() {
+    let buf = [0x12, 0x34, 0x12, 0x34];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_bcd_ordered(2, true), Ok(1234));
+    assert_eq!(reader.read_bcd_ordered(2, false), Ok(2143));
+}
+
+#[test]
+fn read_bcd_ordered_invalid_nibble() {
+    let buf = [0x1F];
+    let mut reader = reader(&buf);
+    assert_eq!(
+        reader.read_bcd_ordered(1, true),
+        Err(untrustended::Error::InvalidValue)
+    );
+}
+
+#[test]
+fn read_ascii_octal() {
+    let buf = b"0000644\0  17 \0\0\0\0";
+    let mut reader = reader(buf);
+    assert_eq!(reader.read_ascii_octal(8), Ok(0o644));
+    assert_eq!(reader.read_ascii_octal(6), Ok(0o17));
+    assert_eq!(
+        reader.read_ascii_octal(3),
+        Err(untrustended::Error::ParseError)
+    );
+}
+
+#[test]
+fn read_ascii_decimal() {
+    let buf = b"  42\0 1 2 0089";
+    let mut reader = reader(buf);
+    assert_eq!(reader.read_ascii_decimal(5), Ok(42));
+    assert_eq!(
+        reader.read_ascii_decimal(5),
+        Err(untrustended::Error::ParseError)
+    );
+    assert_eq!(reader.read_ascii_decimal(4), Ok(89));
+}
+
+#[test]
+fn read_ascii_octal_invalid_digit() {
+    let buf = b"0000648\0";
+    let mut reader = reader(buf);
+    assert_eq!(
+        reader.read_ascii_octal(8),
+        Err(untrustended::Error::ParseError)
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_tbcd() {
+    // MSISDN 31612345678 with filler in the last nibble.
+    let buf = [0x13, 0x16, 0x32, 0x54, 0x76, 0xF8];
+    let mut reader = reader(&buf);
+    assert_eq!(
+        reader.read_tbcd(buf.len()).expect("read_tbcd"),
+        "31612345678"
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_tbcd_extended_digits() {
+    let buf = [0xBA, 0xDC];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_tbcd(buf.len()).expect("read_tbcd"), "*#ab");
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_vlq_u32() {
+    let buf = [0x00, 0x7F, 0x81, 0x00, 0xFF, 0xFF, 0xFF, 0x7F];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_vlq_u32(), Ok(0));
+    assert_eq!(reader.read_vlq_u32(), Ok(0x7F));
+    assert_eq!(reader.read_vlq_u32(), Ok(0x80));
+    assert_eq!(reader.read_vlq_u32(), Ok(0x0FFF_FFFF));
+}
+
+#[test]
+fn read_vlq_u32_overflow() {
+    let buf = [0x81, 0x80, 0x80, 0x80, 0x00];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_vlq_u32(), Err(untrustended::Error::ParseError));
+}
+
+#[test]
+fn read_vlq_i32() {
+    let buf = [0x7F, 0xFE, 0x38, 0x80, 0x40, 0x3F, 0xC0, 0x80, 0x80, 0x00];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_vlq_i32(), Ok(-1));
+    assert_eq!(reader.read_vlq_i32(), Ok(-200));
+    assert_eq!(reader.read_vlq_i32(), Ok(64));
+    assert_eq!(reader.read_vlq_i32(), Ok(63));
+    assert_eq!(reader.read_vlq_i32(), Ok(-(1 << 27)));
+}
+
+#[test]
+fn read_der_tag() {
+    let buf = [
+        0x02, 0xBF, 0x1F, 0x9F, 0x81, 0x48, 0x5F, 0x8F, 0xFF, 0xFF, 0xFF, 0x7F,
+    ];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_der_tag(), Ok(2));
+    assert_eq!(reader.read_der_tag(), Ok(31));
+    assert_eq!(reader.read_der_tag(), Ok(200));
+    assert_eq!(reader.read_der_tag(), Ok(u32::MAX));
+    assert!(reader.at_end());
+}
+
+#[test]
+fn read_der_tag_invalid() {
+    // Leading zero group.
+    let mut leading_zero = reader(&[0x1F, 0x80, 0x01]);
+    assert_eq!(
+        leading_zero.read_der_tag(),
+        Err(untrustended::Error::ParseError)
+    );
+    // Tag number 30 must use the short form.
+    let mut not_minimal = reader(&[0x1F, 0x1E]);
+    assert_eq!(
+        not_minimal.read_der_tag(),
+        Err(untrustended::Error::ParseError)
+    );
+    let mut overflow = reader(&[0x1F, 0x90, 0x80, 0x80, 0x80, 0x00]);
+    assert_eq!(
+        overflow.read_der_tag(),
+        Err(untrustended::Error::ParseError)
+    );
+    let mut truncated = reader(&[0x1F, 0x81]);
+    assert_eq!(
+        truncated.read_der_tag(),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_uleb128() {
+    let buf = [
+        0x00, 0x7F, 0x80, 0x01, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x01, 0x80,
+        0x80, 0x00,
+    ];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_uleb128(), Ok(0));
+    assert_eq!(reader.read_uleb128(), Ok(127));
+    assert_eq!(reader.read_uleb128(), Ok(128));
+    assert_eq!(reader.read_uleb128(), Ok(u64::MAX));
+    // Overlong encoding of 0.
+    assert_eq!(reader.read_uleb128(), Ok(0));
+    assert!(reader.at_end());
+}
+
+#[test]
+fn read_uleb128_invalid() {
+    // Tenth byte carries bits beyond bit 63.
+    let buf = [0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02];
+    let mut too_large = reader(&buf);
+    assert_eq!(
+        too_large.read_uleb128(),
+        Err(untrustended::Error::ParseError)
+    );
+
+    // Overlong encoding longer than ten bytes.
+    let buf = [0x80; 11];
+    let mut overlong = reader(&buf);
+    assert_eq!(
+        overlong.read_uleb128(),
+        Err(untrustended::Error::ParseError)
+    );
+
+    let mut truncated = reader(&[0x80, 0x80]);
+    assert_eq!(
+        truncated.read_uleb128(),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_uleb128_nonzero_u64() {
+    let buf = [0xAC, 0x02, 0x00];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_uleb128_nonzero_u64().map(|v| v.get()), Ok(300));
+    assert_eq!(
+        reader.read_uleb128_nonzero_u64(),
+        Err(untrustended::Error::InvalidValue)
+    );
+}
+
+#[test]
+#[cfg(feature = "use_std")]
+fn read_uleb128_duration_micros() {
+    use std::time::Duration;
+
+    // 1.5 seconds.
+    let buf = [0xE0, 0xC6, 0x5B];
+    let mut reader = reader(&buf);
+    assert_eq!(
+        reader.read_uleb128_duration_micros(),
+        Ok(Duration::from_millis(1500))
+    );
+    assert!(reader.at_end());
+}
+
+#[test]
+#[cfg(feature = "use_std")]
+fn read_duration_secs_u32be() {
+    use std::time::Duration;
+
+    let buf = [0x00, 0x00, 0x0E, 0x10, 0xFF, 0xFF, 0xFF, 0xFF, 0x00];
+    let mut reader = reader(&buf);
+    assert_eq!(
+        reader.read_duration_secs_u32be(),
+        Ok(Duration::from_secs(60 * 60))
+    );
+    assert_eq!(
+        reader.read_duration_secs_u32be(),
+        Ok(Duration::from_secs(u64::from(u32::MAX)))
+    );
+    assert_eq!(
+        reader.read_duration_secs_u32be(),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+#[cfg(feature = "use_std")]
+fn read_duration_millis_u64be() {
+    use std::time::Duration;
+
+    let buf = [
+        0x00, 0x00, 0x00, 0x00, 0x00, 0x36, 0xEE, 0x80, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF,
+        0xFF,
+    ];
+    let mut reader = reader(&buf);
+    assert_eq!(
+        reader.read_duration_millis_u64be(),
+        Ok(Duration::from_secs(60 * 60))
+    );
+    assert_eq!(
+        reader.read_duration_millis_u64be(),
+        Ok(Duration::from_millis(u64::MAX))
+    );
+    assert!(reader.at_end());
+}
+
+#[test]
+fn read_uleb128_generic() {
+    // u64::MAX + 1 needs ten groups, the last one at shift 63.
+    let buf = [
+        0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x02, 0xFF,
+    ];
+    let mut reader = reader(&buf);
+    let mut value = 0u128;
+    let mut shifts = Vec::new();
+    let r = reader.read_uleb128_generic(|shift, bits| {
+        shifts.push(shift);
+        value |= u128::from(bits) << shift;
+        Ok(())
+    });
+    assert_eq!(r, Ok(()));
+    assert_eq!(value, u128::from(u64::MAX) + 1);
+    assert_eq!(shifts, [0, 7, 14, 21, 28, 35, 42, 49, 56, 63]);
+    assert_eq!(
+        reader.read_uleb128_generic(|_, _| Err(untrustended::Error::ParseError)),
+        Err(untrustended::Error::ParseError)
+    );
+}
+
+/// Type-Length-Value record used to test `Readable` helpers.
+#[derive(Debug, PartialEq)]
+struct Data {
+    t: u8,
+    val: Vec<u8>,
+}
+
+impl untrustended::Readable for Data {
+    type Output = Data;
+    fn read(input: &mut Reader<'_>) -> Result<Self::Output, untrustended::Error> {
+        let t = input.read_u8()?;
+        let len = input.read_u8()?;
+        let val = input.read_bytes_less_safe(usize::from(len))?.to_owned();
+        Ok(Data { t, val })
+    }
+}
+
+#[test]
+fn read_readable_array() {
+    let buf = [0x01, 0x01, 0xAA, 0x02, 0x00, 0x03, 0x02, 0xBB, 0xCC, 0xFF];
+    let mut reader = reader(&buf);
+    let data: [Data; 3] = reader
+        .read_readable_array::<Data, 3>()
+        .expect("read_readable_array");
+    assert_eq!(
+        data,
+        [
+            Data {
+                t: 1,
+                val: vec![0xAA]
+            },
+            Data { t: 2, val: vec![] },
+            Data {
+                t: 3,
+                val: vec![0xBB, 0xCC]
+            },
+        ]
+    );
+    assert_eq!(reader.read_u8(), Ok(0xFF));
+}
+
+#[test]
+fn read_readable_array_truncated() {
+    let buf = [0x01, 0x01, 0xAA, 0x02, 0x05];
+    let mut reader = reader(&buf);
+    assert_eq!(
+        reader.read_readable_array::<Data, 2>(),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_vec() {
+    let buf = [0x01, 0x01, 0xAA, 0x02, 0x00, 0xFF];
+    let mut reader = reader(&buf);
+    assert_eq!(
+        reader.read_vec::<Data>(2),
+        Ok(vec![
+            Data {
+                t: 1,
+                val: vec![0xAA]
+            },
+            Data { t: 2, val: vec![] },
+        ])
+    );
+    assert_eq!(reader.read_vec::<Data>(0), Ok(vec![]));
+    assert_eq!(reader.read_u8(), Ok(0xFF));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_vec_huge_count() {
+    let buf = [0x01, 0x01, 0xAA];
+    let mut reader = reader(&buf);
+    assert_eq!(
+        reader.read_vec::<Data>(usize::MAX),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_varint_prefixed_readable() {
+    let buf = [0x04, 0x01, 0x02, 0xAA, 0xBB, 0xFF];
+    let mut reader = reader(&buf);
+    assert_eq!(
+        reader.read_varint_prefixed_readable::<Data>(),
+        Ok(Data {
+            t: 1,
+            val: vec![0xAA, 0xBB]
+        })
+    );
+    assert_eq!(reader.read_u8(), Ok(0xFF));
+}
+
+#[test]
+fn read_varint_prefixed_readable_length_mismatch() {
+    // Declared length is longer than the record.
+    let mut short = reader(&[0x04, 0x01, 0x01, 0xAA, 0xBB]);
+    assert_eq!(
+        short.read_varint_prefixed_readable::<Data>(),
+        Err(untrustended::Error::ParseError)
+    );
+    // Record is longer than the declared length.
+    let mut long = reader(&[0x03, 0x01, 0x02, 0xAA, 0xBB]);
+    assert_eq!(
+        long.read_varint_prefixed_readable::<Data>(),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+/// Header whose byte order depends on the protocol version.
+#[derive(Debug, PartialEq)]
+struct Header {
+    id: u32,
+}
+
+impl untrustended::Readable for Header {
+    type Output = Header;
+    fn read(input: &mut Reader<'_>) -> Result<Self::Output, untrustended::Error> {
+        Self::read_be(input)
+    }
+    fn read_be(input: &mut Reader<'_>) -> Result<Self::Output, untrustended::Error> {
+        Ok(Header {
+            id: input.read_u32be()?,
+        })
+    }
+    fn read_le(input: &mut Reader<'_>) -> Result<Self::Output, untrustended::Error> {
+        Ok(Header {
+            id: input.read_u32le()?,
+        })
+    }
+}
+
+#[test]
+fn readable_read_be_and_le() {
+    use untrustended::Readable;
+
+    let buf = [0x01, 0x02, 0x03, 0x04];
+    let input = Input::from(&buf);
+    let header = input.read_all(untrustended::Error::ParseError, Header::read_be);
+    assert_eq!(header, Ok(Header { id: 0x0102_0304 }));
+    let header = input.read_all(untrustended::Error::ParseError, Header::read_le);
+    assert_eq!(header, Ok(Header { id: 0x0403_0201 }));
+
+    // Byte order independent data structures get both by default.
+    let buf = [0x01, 0x01, 0xAA];
+    let input = Input::from(&buf);
+    let expected = Data {
+        t: 1,
+        val: vec![0xAA],
+    };
+    let data = input.read_all(untrustended::Error::ParseError, Data::read_le);
+    assert_eq!(data.as_ref(), Ok(&expected));
+    let data = input.read_all(untrustended::Error::ParseError, Data::read_be);
+    assert_eq!(data, Ok(expected));
+}
+
+/// Record which gained a `flags` field in version 2.
+#[derive(Debug, PartialEq)]
+struct Versioned {
+    id: u16,
+    flags: Option<u8>,
+}
+
+fn read_versioned_record(input: &mut Reader) -> Result<Versioned, untrustended::Error> {
+    input.read_versioned(
+        |input| input.read_u8().map(u32::from),
+        |version, input| match version {
+            1 => Ok(Versioned {
+                id: input.read_u16be()?,
+                flags: None,
+            }),
+            2 => Ok(Versioned {
+                id: input.read_u16be()?,
+                flags: Some(input.read_u8()?),
+            }),
+            _ => Err(untrustended::Error::InvalidValue),
+        },
+    )
+}
+
+#[test]
+fn read_versioned() {
+    let buf = [0x01, 0x00, 0x0A, 0x02, 0x00, 0x0B, 0x80, 0x03, 0x00];
+    let mut reader = reader(&buf);
+    assert_eq!(
+        read_versioned_record(&mut reader),
+        Ok(Versioned {
+            id: 10,
+            flags: None
+        })
+    );
+    assert_eq!(
+        read_versioned_record(&mut reader),
+        Ok(Versioned {
+            id: 11,
+            flags: Some(0x80)
+        })
+    );
+    assert_eq!(
+        read_versioned_record(&mut reader),
+        Err(untrustended::Error::InvalidValue)
+    );
+}
+
+#[test]
+fn read_sleb128_i64_counted() {
+    let buf = [
+        0x00, 0x7F, 0x80, 0x7F, 0xFF, 0x00, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80, 0x80,
+        0x7F,
+    ];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_sleb128_i64_counted(), Ok((0, 1)));
+    assert_eq!(reader.read_sleb128_i64_counted(), Ok((-1, 1)));
+    assert_eq!(reader.read_sleb128_i64_counted(), Ok((-128, 2)));
+    assert_eq!(reader.read_sleb128_i64_counted(), Ok((127, 2)));
+    assert_eq!(reader.read_sleb128_i64_counted(), Ok((i64::MIN, 10)));
+}
+
+#[test]
+fn read_sleb128_i16() {
+    let buf = [0x7F, 0x80, 0x80, 0x7E, 0xC0, 0xB8, 0x02];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_sleb128_i16(), Ok(-1));
+    assert_eq!(reader.read_sleb128_i16(), Ok(i16::MIN));
+    // 40000 doesn't fit in i16.
+    assert_eq!(
+        reader.read_sleb128_i16(),
+        Err(untrustended::Error::InvalidValue)
+    );
+}
+
+#[test]
+fn read_sint64_varint_counted() {
+    let buf = [0x01, 0x81, 0x01, 0x80, 0x01];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_sint64_varint_counted(), Ok((-1, 1)));
+    assert_eq!(reader.read_sint64_varint_counted(), Ok((-65, 2)));
+    assert_eq!(reader.read_sint64_varint_counted(), Ok((64, 2)));
+}
+
+#[cfg(feature = "alloc")]
+fn read_soa_u8(input: &mut Reader) -> Result<u64, untrustended::Error> {
+    input.read_u8().map(u64::from)
+}
+
+#[cfg(feature = "alloc")]
+fn read_soa_u16le(input: &mut Reader) -> Result<u64, untrustended::Error> {
+    input.read_u16le().map(u64::from)
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_soa() {
+    let buf = [1, 2, 3, 0x10, 0x00, 0x20, 0x00, 0x30, 0x00, 0xFF];
+    let mut reader = reader(&buf);
+    assert_eq!(
+        reader.read_soa::<3>(&[read_soa_u8, read_soa_u16le]),
+        Ok(vec![vec![1, 2, 3], vec![0x10, 0x20, 0x30]])
+    );
+    assert_eq!(
+        reader.read_soa::<2>(&[read_soa_u8]),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_signed_varint() {
+    use untrustended::SignedVarintMode::{Sleb128, Zigzag};
+    let buf = [0x7F, 0x01, 0xC0, 0x00, 0x80, 0x01, 0x80];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_signed_varint(Sleb128), Ok(-1));
+    assert_eq!(reader.read_signed_varint(Zigzag), Ok(-1));
+    assert_eq!(reader.read_signed_varint(Sleb128), Ok(64));
+    assert_eq!(reader.read_signed_varint(Zigzag), Ok(64));
+    assert_eq!(
+        reader.read_signed_varint(Zigzag),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_zigzag_fixed() {
+    let buf = [
+        0x00, 0x00, 0x00, 0x01, 0xFF, 0xFF, 0xFF, 0xFE, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x80, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00,
+    ];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_zigzag_fixed32_be(), Ok(-1));
+    assert_eq!(reader.read_zigzag_fixed32_be(), Ok(i32::MAX));
+    assert_eq!(reader.read_zigzag_fixed64_be(), Ok(64));
+    assert_eq!(reader.read_zigzag_fixed64_be(), Ok(i64::MIN));
+    assert_eq!(
+        reader.read_zigzag_fixed32_be(),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[derive(Debug, PartialEq)]
+enum Opcode {
+    Load,
+    Store,
+}
+
+impl TryFrom<u64> for Opcode {
+    type Error = ();
+    fn try_from(value: u64) -> Result<Self, Self::Error> {
+        match value {
+            0x01_0203 => Ok(Opcode::Load),
+            0x03_0201 => Ok(Opcode::Store),
+            _ => Err(()),
+        }
+    }
+}
+
+#[test]
+fn read_enum_var() {
+    use untrustended::Endianness;
+    let buf = [0x01, 0x02, 0x03, 0x01, 0x02, 0x03, 0x01, 0x02, 0x04];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_enum_var(3, Endianness::Big), Ok(Opcode::Load));
+    assert_eq!(
+        reader.read_enum_var(3, Endianness::Little),
+        Ok(Opcode::Store)
+    );
+    assert_eq!(
+        reader.read_enum_var::<Opcode>(3, Endianness::Big),
+        Err(untrustended::Error::InvalidValue)
+    );
+}
+
+impl Opcode {
+    fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            1 => Some(Opcode::Load),
+            2 => Some(Opcode::Store),
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn read_enum_u8() {
+    let buf = [0x01, 0x02, 0x03];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_enum_u8(Opcode::from_u8), Ok(Opcode::Load));
+    assert_eq!(reader.read_enum_u8(Opcode::from_u8), Ok(Opcode::Store));
+    assert_eq!(
+        reader.read_enum_u8(Opcode::from_u8),
+        Err(untrustended::Error::InvalidValue)
+    );
+    assert_eq!(
+        reader.read_enum_u8(Opcode::from_u8),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_enum_u16be() {
+    let buf = [0x01, 0x00, 0x00, 0x02];
+    let mut reader = reader(&buf);
+    let from_u16 = |v| u8::try_from(v).ok().and_then(Opcode::from_u8);
+    assert_eq!(
+        reader.read_enum_u16be(from_u16),
+        Err(untrustended::Error::InvalidValue)
+    );
+    assert_eq!(reader.read_enum_u16be(from_u16), Ok(Opcode::Store));
+}
+
+#[test]
+fn read_if() {
+    // Flag byte with bit 0 telling whether a u32 timestamp follows.
+    let buf = [0x01, 0x00, 0x00, 0x01, 0x00, 0x00, 0x02, 0xAA, 0x01, 0x00];
+    let mut reader = reader(&buf);
+    let mut timestamps = Vec::new();
+    for _ in 0..3 {
+        let flags = reader.read_u8().expect("read_u8");
+        timestamps.push(reader.read_if(flags & 0x01 != 0, |r| r.read_u32be()));
+    }
+    assert_eq!(timestamps, [Ok(Some(256)), Ok(None), Ok(None)]);
+    assert_eq!(reader.read_u8(), Ok(0xAA));
+
+    let flags = reader.read_u8().expect("read_u8");
+    assert_eq!(
+        reader.read_if(flags & 0x01 != 0, |r| r.read_u32be()),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_scaled_int() {
+    // 23.5 °C in units of 0.1 °C, -40.0 °C and 2 V in units of 1 mV.
+    let buf = [
+        0x00, 0xEB, 0x70, 0xFE, 0xD0, 0x07, 0x00, 0x00, 0xFF, 0xFF, 0xFF, 0xFF,
+    ];
+    let mut reader = reader(&buf);
+    let value = reader.read_scaled_i16be(0.1).expect("read_scaled_i16be");
+    assert!((value - 23.5).abs() < 1e-9);
+    assert_eq!(reader.read_scaled_i16le(0.1), Ok(-40.0));
+    assert_eq!(reader.read_scaled_u32le(0.001), Ok(2.0));
+    assert_eq!(reader.read_scaled_u32be(1.0), Ok(4_294_967_295.0));
+    assert_eq!(
+        reader.read_scaled_u16be(0.5),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_runtime_endianness() {
+    use untrustended::Endianness;
+
+    let buf = [
+        0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08, 0x09, 0x0A, 0x0B, 0x0C, 0x0D, 0x0E, 0x0F,
+        0x10,
+    ];
+    for (endian, u16_value, u32_value, u64_value, u128_value) in [
+        (
+            Endianness::Big,
+            0x0102,
+            0x0102_0304,
+            0x0102_0304_0506_0708,
+            0x0102_0304_0506_0708_090A_0B0C_0D0E_0F10,
+        ),
+        (
+            Endianness::Little,
+            0x0201,
+            0x0403_0201,
+            0x0807_0605_0403_0201,
+            0x100F_0E0D_0C0B_0A09_0807_0605_0403_0201,
+        ),
+    ] {
+        assert_eq!(reader(&buf).read_u16(endian), Ok(u16_value));
+        assert_eq!(reader(&buf).read_u32(endian), Ok(u32_value));
+        assert_eq!(reader(&buf).read_u64(endian), Ok(u64_value));
+        assert_eq!(reader(&buf).read_u128(endian), Ok(u128_value));
+        assert_eq!(reader(&buf).read_i16(endian), Ok(u16_value as i16));
+        assert_eq!(reader(&buf).read_i32(endian), Ok(u32_value as i32));
+        assert_eq!(reader(&buf).read_i64(endian), Ok(u64_value as i64));
+        assert_eq!(reader(&buf).read_i128(endian), Ok(u128_value as i128));
+    }
+
+    let buf = [0xFF, 0xFE];
+    assert_eq!(reader(&buf).read_i16(Endianness::Big), Ok(-2));
+    assert_eq!(reader(&buf).read_i16(Endianness::Little), Ok(-257));
+    assert_eq!(
+        reader(&buf).read_u32(Endianness::Little),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_small_or_extended() {
+    let buf = [0x2A, 0xFF, 0x00, 0x01, 0x00, 0x00];
+    let mut reader = reader(&buf);
+    let extended = |r: &mut Reader<'_>| r.read_u32be().map(u64::from);
+    assert_eq!(reader.read_small_or_extended(0xFF, extended), Ok(42));
+    assert_eq!(reader.read_small_or_extended(0xFF, extended), Ok(65536));
+    assert!(reader.at_end());
+}
+
+#[test]
+fn read_dos_datetime() {
+    // 2024-03-14 12:30:58
+    let buf = [0xDD, 0x63, 0x6E, 0x58];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_dos_datetime(), Ok((2024, 3, 14, 12, 30, 58)));
+}
+
+#[test]
+fn read_dos_datetime_invalid_month() {
+    // Month 13
+    let buf = [0xDD, 0x63, 0xAE, 0x59];
+    let mut reader = reader(&buf);
+    assert_eq!(
+        reader.read_dos_datetime(),
+        Err(untrustended::Error::InvalidValue)
+    );
+}
+
+#[test]
+fn read_iso8601_ascii() {
+    let buf = b"2024-03-14T12:00:00Z2024-02-29T23:59:60Z";
+    let mut reader = reader(buf);
+    assert_eq!(reader.read_iso8601_ascii(), Ok((2024, 3, 14, 12, 0, 0)));
+    assert_eq!(reader.read_iso8601_ascii(), Ok((2024, 2, 29, 23, 59, 60)));
+    assert!(reader.at_end());
+}
+
+#[test]
+fn read_iso8601_ascii_invalid() {
+    let buf = concat!(
+        "2024-13-14T12:00:00Z",
+        "2023-02-29T12:00:00Z",
+        "2024-03-14T24:00:00Z",
+        "2024-03-14 12:00:00Z",
+        "2024-03-1aT12:00:00Z",
+        "2024-03-14T12:00:00",
+    );
+    let mut reader = reader(buf.as_bytes());
+    for expected in [
+        untrustended::Error::InvalidValue,
+        untrustended::Error::InvalidValue,
+        untrustended::Error::InvalidValue,
+        untrustended::Error::ParseError,
+        untrustended::Error::ParseError,
+        untrustended::Error::EndOfInput,
+    ] {
+        assert_eq!(reader.read_iso8601_ascii(), Err(expected));
+    }
+}
+
+#[test]
+fn read_bytes_until() {
+    let buf = b"key=value";
+    let mut reader = reader(buf);
+    assert_eq!(reader.read_bytes_until(b'='), Ok(&b"key"[..]));
+    assert_eq!(
+        reader.read_bytes_until(b'='),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_split_at() {
+    let buf = b"HEADbodybodyX";
+    let mut reader = reader(buf);
+    assert_eq!(
+        reader.read_split_at(4, 12),
+        Ok((&b"HEAD"[..], &b"bodybody"[..]))
+    );
+    assert_eq!(
+        reader.read_split_at(2, 1),
+        Err(untrustended::Error::InvalidLength)
+    );
+    assert_eq!(
+        reader.read_split_at(0, 2),
+        Err(untrustended::Error::EndOfInput)
+    );
+    assert_eq!(reader.read_split_at(0, 1), Ok((&b""[..], &b"X"[..])));
+}
+
+#[test]
+fn read_length_prefixed() {
+    let buf = [
+        0x00, // u8 length
+        0x02, 0xAA, 0xBB, // u8 length and bytes
+        0x00, 0x00, 0x00, 0x01, 0xCC, // u32 length and bytes
+        0x00, 0x00, // u16 length
+        0x00, 0x05, 0xDD, // u16 length exceeding the input
+    ];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_length_prefixed_u8(), Ok(&[][..]));
+    assert_eq!(reader.read_length_prefixed_u8(), Ok(&[0xAA, 0xBB][..]));
+    assert_eq!(reader.read_length_prefixed_u32be(), Ok(&[0xCC][..]));
+    assert_eq!(reader.read_length_prefixed_u16be(), Ok(&[][..]));
+    assert_eq!(
+        reader.read_length_prefixed_u16be(),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_framed() {
+    use untrustended::Endianness;
+
+    let buf = [
+        0xAA, 0x55, 0x00, 0x03, 0x01, 0x02, 0x03, 0xAA, 0x55, 0x01, 0x00, 0x04,
+    ];
+    let mut reader = reader(&buf);
+    assert_eq!(
+        reader.read_framed(&[0xAA, 0x55], 2, Endianness::Big),
+        Ok(&[0x01, 0x02, 0x03][..])
+    );
+    assert_eq!(
+        reader.read_framed(&[0xAA, 0x55], 2, Endianness::Little),
+        Ok(&[0x04][..])
+    );
+    assert!(reader.at_end());
+}
+
+#[test]
+fn read_framed_invalid() {
+    use untrustended::Endianness;
+
+    let mut bad_sync = reader(&[0xAA, 0x56, 0x00, 0x00]);
+    assert_eq!(
+        bad_sync.read_framed(&[0xAA, 0x55], 2, Endianness::Big),
+        Err(untrustended::Error::InvalidValue)
+    );
+    let mut too_long = reader(&[0xAA, 0x55, 0x00, 0x03, 0x01, 0x02]);
+    assert_eq!(
+        too_long.read_framed(&[0xAA, 0x55], 2, Endianness::Big),
+        Err(untrustended::Error::InvalidLength)
+    );
+    let mut reader = reader(&[0xAA, 0x55]);
+    assert_eq!(
+        reader.read_framed(&[0xAA, 0x55], 9, Endianness::Big),
+        Err(untrustended::Error::ParseError)
+    );
+}
+
+#[test]
+fn skip_bytes() {
+    let buf = [0x00, 0x00, 0x00, 0x00, 0x2A, 0x01];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.skip_bytes(4), Ok(()));
+    assert_eq!(reader.read_u8(), Ok(0x2A));
+    assert_eq!(reader.skip_bytes(2), Err(untrustended::Error::EndOfInput));
+    assert_eq!(reader.read_u8(), Ok(0x01));
+    assert_eq!(reader.skip_bytes(0), Ok(()));
+}
+
+#[test]
+fn read_remaining() {
+    let buf = b"\x01rest";
+    let mut reader = reader(buf);
+    assert_eq!(reader.read_u8(), Ok(1));
+    assert_eq!(reader.read_remaining(), Ok(&b"rest"[..]));
+    assert!(reader.at_end());
+    assert_eq!(reader.read_remaining(), Ok(&b""[..]));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_remaining_chunks() {
+    let buf = [0xFF, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12];
+    let mut records = reader(&buf);
+    assert_eq!(records.read_u8(), Ok(0xFF));
+    assert_eq!(
+        records.read_remaining_chunks(4),
+        Ok(vec![&buf[1..5], &buf[5..9], &buf[9..13]])
+    );
+    assert!(records.at_end());
+
+    let mut odd = reader(&buf);
+    assert_eq!(
+        odd.read_remaining_chunks(4),
+        Err(untrustended::Error::InvalidLength)
+    );
+
+    let mut zero = reader(&buf);
+    assert_eq!(
+        zero.read_remaining_chunks(0),
+        Err(untrustended::Error::ParseError)
+    );
+}
+
+#[test]
+fn read_partial_leftover() {
+    use untrustended::{read_partial, Error};
+
+    // Header of version and length followed by a payload.
+    let buf = [0x01, 0x00, 0x00, 0x04, 0xDE, 0xAD, 0xBE, 0xEF];
+    let header = |input: &mut Reader<'_>| Ok((input.read_u8()?, input.read_u24be()?));
+    assert_eq!(
+        read_partial(Input::from(&buf), Error::UnknownError, header),
+        Ok(((1, 4), &buf[4..]))
+    );
+    assert_eq!(
+        read_partial(Input::from(&buf[..4]), Error::UnknownError, header),
+        Ok(((1, 4), &[][..]))
+    );
+    assert_eq!(
+        read_partial(Input::from(&buf[..3]), Error::InvalidLength, header),
+        Err(Error::InvalidLength)
+    );
+}
+
+#[test]
+fn read_exact_or_rest() {
+    let buf = b"abcdefg";
+    let mut reader = reader(buf);
+    assert_eq!(reader.read_exact_or_rest(4), Ok(&b"abcd"[..]));
+    assert_eq!(reader.read_exact_or_rest(4), Ok(&b"efg"[..]));
+    assert!(reader.at_end());
+    assert_eq!(reader.read_exact_or_rest(4), Ok(&b""[..]));
+}
+
+#[test]
+fn read_crc16_ccitt_verify() {
+    let buf = b"123456789\x29\xB1\x29\xB2";
+    let mut reader = reader(buf);
+    let data = reader.read_bytes_less_safe(9).expect("read data");
+    assert_eq!(reader.read_crc16_ccitt_verify(data), Ok(()));
+    assert_eq!(
+        reader.read_crc16_ccitt_verify(data),
+        Err(untrustended::Error::InvalidValue)
+    );
+    assert_eq!(
+        reader.read_crc16_ccitt_verify(data),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn expect_bytes() {
+    let buf = b"\x89PNG\r\n\x1a\n\x89png";
+    let mut png = reader(buf);
+    assert_eq!(png.expect_bytes(b"\x89PNG\r\n\x1a\n"), Ok(()));
+    assert_eq!(
+        png.expect_bytes(b"\x89PNG"),
+        Err(untrustended::Error::InvalidValue)
+    );
+
+    let mut truncated = reader(b"\x89PN");
+    assert_eq!(
+        truncated.expect_bytes(b"\x89PNG"),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_matching_ascii_ci() {
+    let buf = b"http/1.1 HTTQ";
+    let mut reader = reader(buf);
+    assert_eq!(reader.read_matching_ascii_ci(b"HTTP/1.1 "), Ok(()));
+    assert_eq!(
+        reader.read_matching_ascii_ci(b"HTTP"),
+        Err(untrustended::Error::InvalidValue)
+    );
+    assert_eq!(
+        reader.read_matching_ascii_ci(b"a"),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_bytes_until_pattern() {
+    let buf = b"GET / HTTP/1.1\r\nHost: a\r\n\r\nbody";
+    let mut reader = reader(buf);
+    assert_eq!(
+        reader.read_bytes_until_pattern(b"\r\n\r\n"),
+        Ok(&b"GET / HTTP/1.1\r\nHost: a"[..])
+    );
+    assert_eq!(reader.read_bytes_less_safe(4), Ok(&b"body"[..]));
+    assert!(reader.at_end());
+}
+
+#[test]
+fn read_bytes_until_pattern_overlapping() {
+    let buf = b"aaabaabaabx";
+    let mut reader = reader(buf);
+    assert_eq!(reader.read_bytes_until_pattern(b"aab"), Ok(&b"a"[..]));
+    assert_eq!(reader.read_bytes_until_pattern(b"aabaabx"), Ok(&b""[..]));
+    assert_eq!(
+        reader.read_bytes_until_pattern(b""),
+        Err(untrustended::Error::ParseError)
+    );
+    assert_eq!(
+        reader.read_bytes_until_pattern(b"x"),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_printable_utf8() {
+    let buf = b"Host: example\tok\x07";
+    let mut reader = reader(buf);
+    assert_eq!(reader.read_printable_utf8(13, &[]), Ok("Host: example"));
+    assert_eq!(reader.read_printable_utf8(3, &['\t']), Ok("\tok"));
+    assert_eq!(
+        reader.read_printable_utf8(1, &['\t']),
+        Err(untrustended::Error::InvalidValue)
+    );
+}
+
+#[test]
+fn read_printable_utf8_rejects_c1_control() {
+    let buf = "a\u{85}".as_bytes();
+    let mut reader = reader(buf);
+    assert_eq!(
+        reader.read_printable_utf8(buf.len(), &[]),
+        Err(untrustended::Error::InvalidValue)
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_bytes_cow() {
+    use std::borrow::Cow;
+    let buf = [1, 2, 3];
+    let mut reader = reader(&buf);
+    let bytes = reader.read_bytes_cow(2).expect("read_bytes_cow");
+    assert!(matches!(bytes, Cow::Borrowed(&[1, 2])));
+    assert_eq!(bytes.into_owned(), vec![1, 2]);
+    assert_eq!(
+        reader.read_bytes_cow(2),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_ascii() {
+    let buf = b"HELO\x7F\tabc\x80";
+    let mut reader = reader(buf);
+    assert_eq!(reader.read_ascii(6), Ok("HELO\x7F\t"));
+    assert_eq!(reader.read_ascii(4), Err(untrustended::Error::InvalidValue));
+    assert_eq!(reader.read_ascii(1), Err(untrustended::Error::EndOfInput));
+}
+
+#[test]
+fn read_char_u32be() {
+    let buf = [
+        0x00, 0x00, 0x00, 0x41, // 'A'
+        0x00, 0x01, 0xF6, 0x00, // U+1F600 GRINNING FACE
+        0x00, 0x00, 0xD8, 0x00, // surrogate
+        0x00, 0x11, 0x00, 0x00, // past U+10FFFF
+        0x00, 0x00,
+    ];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_char_u32be(), Ok('A'));
+    assert_eq!(reader.read_char_u32be(), Ok('\u{1F600}'));
+    assert_eq!(
+        reader.read_char_u32be(),
+        Err(untrustended::Error::InvalidValue)
+    );
+    assert_eq!(
+        reader.read_char_u32be(),
+        Err(untrustended::Error::InvalidValue)
+    );
+    assert_eq!(
+        reader.read_char_u32be(),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_char_u32le() {
+    let buf = [
+        0x41, 0x00, 0x00, 0x00, // 'A'
+        0x00, 0xF6, 0x01, 0x00, // U+1F600 GRINNING FACE
+        0xFF, 0xDF, 0x00, 0x00, // surrogate
+        0x00, 0x00, 0x11, 0x00, // past U+10FFFF
+    ];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_char_u32le(), Ok('A'));
+    assert_eq!(reader.read_char_u32le(), Ok('\u{1F600}'));
+    assert_eq!(
+        reader.read_char_u32le(),
+        Err(untrustended::Error::InvalidValue)
+    );
+    assert_eq!(
+        reader.read_char_u32le(),
+        Err(untrustended::Error::InvalidValue)
+    );
+}
+
+#[test]
+fn read_cstr_utf8() {
+    let buf = b"\0h\xC3\xA9llo\0\xFF\0abc";
+    let mut reader = reader(buf);
+    assert_eq!(reader.read_cstr_utf8(), Ok(""));
+    assert_eq!(reader.read_cstr_utf8(), Ok("héllo"));
+    assert_eq!(
+        reader.read_cstr_utf8(),
+        Err(untrustended::Error::ParseError)
+    );
+    assert_eq!(
+        reader.read_cstr_utf8(),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_cstr_list() {
+    let buf = b"A\0B\0\0C";
+    let mut reader = reader(buf);
+    assert_eq!(reader.read_cstr_list(), Ok(vec!["A", "B"]));
+    assert_eq!(reader.read_u8(), Ok(b'C'));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_cstr_list_without_terminator() {
+    let buf = b"A\0B\0";
+    let mut reader = reader(buf);
+    assert_eq!(
+        reader.read_cstr_list(),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_bitmap() {
+    let buf = [0b1000_0001, 0b0110_1111];
+    let mut reader = reader(&buf);
+    assert_eq!(
+        reader.read_bitmap(12),
+        Ok(vec![
+            true, false, false, false, false, false, false, true, false, true, true, false,
+        ])
+    );
+    assert!(reader.at_end());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_fields_rtp_header() {
+    let specs = [
+        ("version", 2),
+        ("padding", 1),
+        ("extension", 1),
+        ("csrc_count", 4),
+        ("marker", 1),
+        ("payload_type", 7),
+        ("sequence", 16),
+        ("timestamp", 32),
+        ("ssrc", 32),
+    ];
+    let buf = [
+        0x80, 0xE0, 0x12, 0x34, 0x00, 0x00, 0x03, 0xE8, 0xDE, 0xAD, 0xBE, 0xEF, 0xFF,
+    ];
+    let mut reader = reader(&buf);
+    assert_eq!(
+        reader.read_fields(&specs),
+        Ok(vec![
+            ("version", 2),
+            ("padding", 0),
+            ("extension", 0),
+            ("csrc_count", 0),
+            ("marker", 1),
+            ("payload_type", 96),
+            ("sequence", 0x1234),
+            ("timestamp", 1000),
+            ("ssrc", 0xDEAD_BEEF),
+        ])
+    );
+    assert_eq!(reader.read_u8(), Ok(0xFF));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_fields_invalid() {
+    let buf = [0xFF; 9];
+    let mut reader = reader(&buf);
+    assert_eq!(
+        reader.read_fields(&[("wide", 65)]),
+        Err(untrustended::Error::ParseError)
+    );
+    assert_eq!(
+        reader.read_fields(&[("a", 64), ("b", 9)]),
+        Err(untrustended::Error::EndOfInput)
+    );
+    assert_eq!(
+        reader.read_fields(&[("a", 64), ("b", 3)]),
+        Ok(vec![("a", u64::MAX), ("b", 7)])
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_until_sentinel_u32be() {
+    let buf = [
+        0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02, 0xFF, 0xFF, 0xFF, 0xFF, 0x00, 0x00, 0x00,
+        0x03,
+    ];
+    let mut reader = reader(&buf);
+    assert_eq!(reader.read_until_sentinel_u32be(u32::MAX), Ok(vec![1, 2]));
+    assert_eq!(
+        reader.read_until_sentinel_u32be(u32::MAX),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_varint_prefixed_utf8_list() {
+    let buf = b"\x05hello\x06w\xC3\xB6rld\x00\x02\xFF\xFE";
+    let mut reader = reader(buf);
+    assert_eq!(
+        reader.read_varint_prefixed_utf8_list(3),
+        Ok(vec!["hello", "wörld", ""])
+    );
+    assert_eq!(
+        reader.read_varint_prefixed_utf8_list(1),
+        Err(untrustended::Error::ParseError)
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_length_delimited_records() {
+    let buf = b"\x03abc\x00\x02de";
+    let mut reader = reader(buf);
+    assert_eq!(
+        reader.read_length_delimited_records(3),
+        Ok(vec![&b"abc"[..], &b""[..], &b"de"[..]])
+    );
+    assert!(reader.at_end());
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_length_delimited_records_errors() {
+    let mut reader = reader(b"\x01a\x04abcd");
+    assert_eq!(
+        reader.read_length_delimited_records(3),
+        Err(untrustended::Error::InvalidLength)
+    );
+    let mut truncated = Reader::new(Input::from(b"\x01a\x03ab"));
+    assert_eq!(
+        truncated.read_length_delimited_records(3),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_uleb128_vec_bounded() {
+    let buf = [0x01, 0xAC, 0x02, 0x7F];
+    let mut within = reader(&buf);
+    assert_eq!(within.read_uleb128_vec_bounded(3, 4), Ok(vec![1, 300, 127]));
+    assert!(within.at_end());
+
+    let mut too_many = reader(&buf);
+    assert_eq!(
+        too_many.read_uleb128_vec_bounded(2, 100),
+        Err(untrustended::Error::InvalidLength)
+    );
+    let mut too_long = reader(&buf);
+    assert_eq!(
+        too_long.read_uleb128_vec_bounded(100, 3),
+        Err(untrustended::Error::InvalidLength)
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_varint_prefixed_utf8_list_huge_count() {
+    let buf = b"\x01a\x80";
+    let mut reader = reader(buf);
+    assert_eq!(
+        reader.read_varint_prefixed_utf8_list(usize::MAX),
+        Err(untrustended::Error::EndOfInput)
+    );
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_packed_bools() {
+    let buf = [0b1010_0001, 0b0111_1111, 0xAA];
+    let mut reader = reader(&buf);
+    let flags = reader.read_packed_bools(10).expect("read_packed_bools");
+    assert_eq!(
+        flags,
+        vec![true, false, true, false, false, false, false, true, false, true]
+    );
+    assert_eq!(reader.read_u8(), Ok(0xAA));
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_tbcd_digit_after_filler() {
+    let buf = [0xF1, 0x32];
+    let mut reader = reader(&buf);
+    assert_eq!(
+        reader.read_tbcd(buf.len()),
+        Err(untrustended::Error::InvalidValue)
+    );
+}
+
+#[test]
+#[cfg(feature = "use_std")]
+fn error_into_io_error() {
+    use std::io::ErrorKind;
+    use untrustended::Error;
+
+    let cases = [
+        (Error::EndOfInput, ErrorKind::UnexpectedEof),
+        (Error::ParseError, ErrorKind::InvalidData),
+        (Error::InvalidValue, ErrorKind::InvalidData),
+        (Error::InvalidLength, ErrorKind::InvalidData),
+        (
+            Error::Incomplete {
+                needed: 4,
+                available: 2,
+            },
+            ErrorKind::UnexpectedEof,
+        ),
+        (
+            Error::LengthExceedsInput {
+                needed: 4,
+                available: 2,
+            },
+            ErrorKind::InvalidData,
+        ),
+        (Error::UnknownError, ErrorKind::Other),
+    ];
+    for (error, kind) in cases {
+        let message = error.to_string();
+        let io_error = std::io::Error::from(error);
+        assert_eq!(io_error.kind(), kind);
+        assert_eq!(io_error.to_string(), message);
+    }
+}
+
+#[test]
+#[cfg(feature = "use_std")]
+fn error_question_mark_into_io_result() {
+    fn parse(buf: &[u8]) -> std::io::Result<u16> {
+        Ok(reader(buf).read_u16be()?)
+    }
+
+    assert_eq!(parse(&[0x01, 0x02]).expect("parse"), 0x0102);
+    assert_eq!(
+        parse(&[0x01]).map_err(|e| e.kind()),
+        Err(std::io::ErrorKind::UnexpectedEof)
+    );
+}
@@ -0,0 +1,109 @@
+#![deny(warnings)]
+
+use untrusted::{Input, Reader};
+use untrustended::msgpack;
+use untrustended::Error;
+
+#[test]
+fn read_msgpack_int() {
+    let buf = [
+        0x00, // positive fixint 0
+        0x7F, // positive fixint 127
+        0xCC, 0xFF, // uint 8
+        0xCD, 0x01, 0x00, // uint 16
+        0xCE, 0xFF, 0xFF, 0xFF, 0xFF, // uint 32
+        0xCF, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, // uint 64
+        0xFF, // negative fixint -1
+        0xE0, // negative fixint -32
+        0xD0, 0x80, // int 8
+        0xD1, 0xFF, 0x00, // int 16
+        0xD2, 0x80, 0x00, 0x00, 0x00, // int 32
+        0xD3, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, // int 64
+    ];
+    let expected = [
+        0,
+        127,
+        255,
+        256,
+        0xFFFF_FFFF,
+        i64::MAX,
+        -1,
+        -32,
+        -128,
+        -256,
+        i64::from(i32::MIN),
+        i64::MIN,
+    ];
+    let mut reader = Reader::new(Input::from(&buf));
+    for value in expected {
+        assert_eq!(msgpack::read_msgpack_int(&mut reader), Ok(value));
+    }
+    assert!(reader.at_end());
+}
+
+#[test]
+fn read_msgpack_int_invalid() {
+    // uint 64 larger than i64::MAX.
+    let buf = [0xCF, 0x80, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00];
+    let mut reader = Reader::new(Input::from(&buf));
+    assert_eq!(
+        msgpack::read_msgpack_int(&mut reader),
+        Err(Error::InvalidValue)
+    );
+
+    // fixstr isn't an integer.
+    let mut reader = Reader::new(Input::from(&[0xA1, b'a']));
+    assert_eq!(
+        msgpack::read_msgpack_int(&mut reader),
+        Err(Error::InvalidValue)
+    );
+
+    let mut reader = Reader::new(Input::from(&[0xCD, 0x01]));
+    assert_eq!(
+        msgpack::read_msgpack_int(&mut reader),
+        Err(Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_msgpack_str() {
+    let mut buf = vec![0xA5];
+    buf.extend_from_slice(b"hello");
+    buf.extend_from_slice(&[0xA0, 0xD9, 0x20]);
+    buf.extend_from_slice(&[b'x'; 32]);
+    buf.extend_from_slice(&[0xDA, 0x00, 0x02]);
+    buf.extend_from_slice("é".as_bytes());
+    buf.extend_from_slice(&[0xDB, 0x00, 0x00, 0x00, 0x01, b'!']);
+    let mut reader = Reader::new(Input::from(&buf));
+    assert_eq!(msgpack::read_msgpack_str(&mut reader), Ok("hello"));
+    assert_eq!(msgpack::read_msgpack_str(&mut reader), Ok(""));
+    assert_eq!(
+        msgpack::read_msgpack_str(&mut reader),
+        Ok("xxxxxxxxxxxxxxxxxxxxxxxxxxxxxxxx")
+    );
+    assert_eq!(msgpack::read_msgpack_str(&mut reader), Ok("é"));
+    assert_eq!(msgpack::read_msgpack_str(&mut reader), Ok("!"));
+    assert!(reader.at_end());
+}
+
+#[test]
+fn read_msgpack_str_invalid() {
+    // Integer isn't a string.
+    let mut reader = Reader::new(Input::from(&[0x01]));
+    assert_eq!(
+        msgpack::read_msgpack_str(&mut reader),
+        Err(Error::InvalidValue)
+    );
+
+    let mut reader = Reader::new(Input::from(&[0xA1, 0xFF]));
+    assert_eq!(
+        msgpack::read_msgpack_str(&mut reader),
+        Err(Error::ParseError)
+    );
+
+    let mut reader = Reader::new(Input::from(&[0xD9, 0x02, b'a']));
+    assert_eq!(
+        msgpack::read_msgpack_str(&mut reader),
+        Err(Error::EndOfInput)
+    );
+}
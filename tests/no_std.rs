@@ -0,0 +1,20 @@
+#![no_std]
+#![deny(warnings)]
+
+use untrusted::Input;
+use untrustended::{Error, ReaderExt};
+
+#[test]
+fn read_utf8() {
+    let buf = "größe\0".as_bytes();
+    let value = Input::from(buf).read_all(Error::UnknownError, |input| {
+        let s = input.read_utf8(7)?;
+        input.read_u8()?;
+        Ok(s)
+    });
+    assert_eq!(value, Ok("größe"));
+
+    let buf = [0x61, 0xC3];
+    let value = Input::from(&buf).read_all(Error::UnknownError, |input| input.read_utf8(2));
+    assert_eq!(value, Err(Error::ParseError));
+}
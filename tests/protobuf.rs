@@ -0,0 +1,47 @@
+#![deny(warnings)]
+
+use untrusted::Input;
+use untrustended::protobuf::{self, WIRE_TYPE_VARINT};
+use untrustended::{Error, ReaderExt};
+
+#[test]
+fn read_protobuf_message_skips_unknown_fields() {
+    // Field 1 (varint) = 150, field 3 (len) = "abc", field 2 (fixed64) = 7
+    let buf = [
+        0x08, 0x96, 0x01, 0x1A, 0x03, b'a', b'b', b'c', 0x11, 0x07, 0x00, 0x00, 0x00, 0x00, 0x00,
+        0x00, 0x00,
+    ];
+    let mut seen = Vec::new();
+    let value = Input::from(&buf)
+        .read_all(Error::UnknownError, |input| {
+            let mut value = None;
+            protobuf::read_protobuf_message(input, |field, wire_type, input| {
+                seen.push((field, wire_type));
+                if field != 2 {
+                    return Ok(false);
+                }
+                value = Some(input.read_u64le()?);
+                Ok(true)
+            })?;
+            Ok(value)
+        })
+        .expect("read_protobuf_message");
+    assert_eq!(value, Some(7));
+    assert_eq!(seen, vec![(1, WIRE_TYPE_VARINT), (3, 2), (2, 1)]);
+}
+
+#[test]
+fn read_protobuf_message_rejects_groups() {
+    let buf = [0x0B, 0x0C];
+    let res = Input::from(&buf).read_all(Error::UnknownError, |input| {
+        protobuf::read_protobuf_message(input, |_, _, _| Ok(false))
+    });
+    assert_eq!(res, Err(Error::InvalidValue));
+}
+
+#[test]
+fn read_protobuf_tag_rejects_field_zero() {
+    let buf = [0x00];
+    let res = Input::from(&buf).read_all(Error::UnknownError, protobuf::read_protobuf_tag);
+    assert_eq!(res, Err(Error::InvalidValue));
+}
@@ -0,0 +1,64 @@
+#![deny(warnings)]
+
+use untrusted::{Input, Reader};
+use untrustended::riff::{self, WavFmt};
+use untrustended::{read_partial, Error};
+
+/// "fmt " chunk body of 16 bit stereo PCM at 44.1 kHz.
+const PCM_FMT: [u8; 16] = [
+    0x01, 0x00, 0x02, 0x00, 0x44, 0xAC, 0x00, 0x00, 0x10, 0xB1, 0x02, 0x00, 0x04, 0x00, 0x10, 0x00,
+];
+
+#[test]
+fn read_wav_fmt() {
+    let mut reader = Reader::new(Input::from(&PCM_FMT));
+    assert_eq!(
+        riff::read_wav_fmt(&mut reader),
+        Ok(WavFmt {
+            audio_format: 1,
+            channels: 2,
+            sample_rate: 44_100,
+            byte_rate: 176_400,
+            block_align: 4,
+            bits_per_sample: 16,
+        })
+    );
+    assert!(reader.at_end());
+}
+
+#[test]
+fn read_wav_fmt_invalid() {
+    let mut no_channels = PCM_FMT;
+    no_channels[2] = 0;
+    let mut reader = Reader::new(Input::from(&no_channels));
+    assert_eq!(riff::read_wav_fmt(&mut reader), Err(Error::InvalidValue));
+
+    let mut odd_bits = PCM_FMT;
+    odd_bits[14] = 12;
+    let mut reader = Reader::new(Input::from(&odd_bits));
+    assert_eq!(riff::read_wav_fmt(&mut reader), Err(Error::InvalidValue));
+
+    let mut reader = Reader::new(Input::from(&PCM_FMT[..15]));
+    assert_eq!(riff::read_wav_fmt(&mut reader), Err(Error::EndOfInput));
+}
+
+#[test]
+fn read_wav_fmt_extensible() {
+    // WAVE_FORMAT_EXTENSIBLE: cbSize of 22 and the extension follow the
+    // fields common to all formats.
+    let mut buf = PCM_FMT.to_vec();
+    buf[0] = 0xFE;
+    buf[1] = 0xFF;
+    buf.extend_from_slice(&[22, 0]);
+    buf.extend_from_slice(&[0xAA; 22]);
+    let (fmt, extension) = read_partial(Input::from(&buf), Error::ParseError, riff::read_wav_fmt)
+        .expect("read_wav_fmt to succeed");
+    assert_eq!(fmt.audio_format, 0xFFFE);
+    assert_eq!(fmt.sample_rate, 44_100);
+    assert_eq!(extension.len(), 24);
+
+    assert_eq!(
+        Input::from(&buf).read_all(Error::ParseError, riff::read_wav_fmt),
+        Err(Error::ParseError)
+    );
+}
@@ -0,0 +1,54 @@
+#![deny(warnings)]
+
+use untrusted::{Input, Reader};
+use untrustended::tar::{self, BLOCK_SIZE};
+use untrustended::Error;
+
+/// Header of `hello.txt` archived with GNU tar in ustar format.
+const HEADER: &[u8; BLOCK_SIZE] = include_bytes!("data/ustar_header.bin");
+
+#[test]
+fn read_tar_header() {
+    let mut reader = Reader::new(Input::from(HEADER));
+    let header = tar::read_tar_header(&mut reader).expect("read_tar_header");
+    assert!(reader.at_end());
+    assert_eq!(header.name, b"hello.txt");
+    assert_eq!(header.mode, 0o644);
+    assert_eq!(header.uid, 1000);
+    assert_eq!(header.gid, 100);
+    assert_eq!(header.size, 6);
+    assert_eq!(header.mtime, 1_704_164_645);
+    assert_eq!(header.typeflag, b'0');
+    assert_eq!(header.linkname, b"");
+    assert_eq!(header.magic, b"ustar\0");
+    assert_eq!(header.version, b"00");
+    assert_eq!(header.uname, b"alice");
+    assert_eq!(header.gname, b"users");
+    assert_eq!(header.devmajor, 0);
+    assert_eq!(header.devminor, 0);
+    assert_eq!(header.prefix, b"");
+}
+
+#[test]
+fn read_tar_header_checksum_mismatch() {
+    let mut buf = *HEADER;
+    buf[0] = b'j';
+    let mut reader = Reader::new(Input::from(&buf));
+    assert_eq!(tar::read_tar_header(&mut reader), Err(Error::InvalidValue));
+}
+
+#[test]
+fn read_tar_header_checksum_field_ignored() {
+    // Only the value of the checksum field matters, not its padding.
+    let mut buf = *HEADER;
+    buf[154] = 0;
+    buf[155] = 0;
+    let mut reader = Reader::new(Input::from(&buf));
+    assert!(tar::read_tar_header(&mut reader).is_ok());
+}
+
+#[test]
+fn read_tar_header_truncated() {
+    let mut reader = Reader::new(Input::from(&HEADER[..BLOCK_SIZE - 1]));
+    assert_eq!(tar::read_tar_header(&mut reader), Err(Error::EndOfInput));
+}
@@ -0,0 +1,54 @@
+#![deny(warnings)]
+
+use untrusted::{Input, Reader};
+use untrustended::thrift::{self, TYPE_BINARY, TYPE_I32, TYPE_I64, TYPE_STOP};
+use untrustended::Error;
+
+#[test]
+fn read_compact_struct() {
+    // Field 1 (i32) = 150, field 3 (i64) = -1, field 300 (binary) = "", stop
+    let buf = [0x15, 0xAC, 0x02, 0x26, 0x01, 0x08, 0xD8, 0x04, 0x00, 0x00];
+    let mut reader = Reader::new(Input::from(&buf));
+    assert_eq!(
+        thrift::read_compact_field_header(&mut reader),
+        Ok((1, TYPE_I32))
+    );
+    assert_eq!(thrift::read_compact_i32(&mut reader), Ok(150));
+    assert_eq!(
+        thrift::read_compact_field_header(&mut reader),
+        Ok((2, TYPE_I64))
+    );
+    assert_eq!(thrift::read_compact_i64(&mut reader), Ok(-1));
+    assert_eq!(
+        thrift::read_compact_field_header(&mut reader),
+        Ok((0, TYPE_BINARY))
+    );
+    assert_eq!(thrift::read_compact_i16(&mut reader), Ok(300));
+    assert_eq!(reader.read_byte(), Ok(0)); // empty binary
+    assert_eq!(
+        thrift::read_compact_field_header(&mut reader),
+        Ok((0, TYPE_STOP))
+    );
+    assert!(reader.at_end());
+}
+
+#[test]
+fn read_compact_field_header_invalid_stop() {
+    let buf = [0x10];
+    let mut reader = Reader::new(Input::from(&buf));
+    assert_eq!(
+        thrift::read_compact_field_header(&mut reader),
+        Err(Error::InvalidValue)
+    );
+}
+
+#[test]
+fn read_compact_i32_out_of_range() {
+    // 2^32 doesn't fit in 32 bits
+    let buf = [0x80, 0x80, 0x80, 0x80, 0x10];
+    let mut reader = Reader::new(Input::from(&buf));
+    assert_eq!(
+        thrift::read_compact_i32(&mut reader),
+        Err(Error::ParseError)
+    );
+}
@@ -0,0 +1,371 @@
+#![deny(warnings)]
+
+use untrusted::{Input, Reader};
+use untrustended::{Error, FromReader, OffsetError, ReaderExt, TrackingReader};
+
+#[test]
+fn read_aligned_be_skips_padding() {
+    let buf = [0x01, 0xAA, 0xBB, 0xCC, 0x00, 0x00, 0x01, 0x00];
+    let mut reader = TrackingReader::new(Input::from(&buf));
+    assert_eq!(reader.read_u8(), Ok(1));
+    assert_eq!(reader.position(), 1);
+    assert_eq!(reader.read_aligned_be::<u32>(), Ok(256));
+    assert_eq!(reader.position(), 8);
+    assert!(reader.at_end());
+}
+
+struct Rgb([u8; 3]);
+
+impl FromReader for Rgb {
+    fn read_be(reader: &mut Reader<'_>) -> Result<Self, Error> {
+        Ok(Rgb([
+            reader.read_u8()?,
+            reader.read_u8()?,
+            reader.read_u8()?,
+        ]))
+    }
+
+    fn read_le(reader: &mut Reader<'_>) -> Result<Self, Error> {
+        let [b, g, r] = Self::read_be(reader)?.0;
+        Ok(Rgb([r, g, b]))
+    }
+}
+
+#[test]
+fn read_aligned_rounds_size_up_to_power_of_two() {
+    let buf = [
+        0x01, 0xAA, 0xBB, 0xCC, 0x10, 0x20, 0x30, 0xDD, 0x10, 0x20, 0x30,
+    ];
+    let mut reader = TrackingReader::new(Input::from(&buf));
+    assert_eq!(reader.read_u8(), Ok(1));
+    assert_eq!(
+        reader.read_aligned_be::<Rgb>().map(|c| c.0),
+        Ok([0x10, 0x20, 0x30])
+    );
+    assert_eq!(reader.position(), 7);
+    assert_eq!(
+        reader.read_aligned_le::<Rgb>().map(|c| c.0),
+        Ok([0x30, 0x20, 0x10])
+    );
+    assert!(reader.at_end());
+}
+
+#[test]
+fn read_aligned_le_when_already_aligned() {
+    let buf = [0x01, 0x00, 0x02, 0x00];
+    let mut reader = TrackingReader::new(Input::from(&buf));
+    assert_eq!(reader.read_aligned_le::<u16>(), Ok(1));
+    assert_eq!(reader.read_aligned_le::<u16>(), Ok(2));
+    assert_eq!(reader.position(), 4);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn remaining_hexdump() {
+    let buf = b"\x00\x01Hello, world! How are you?";
+    let mut reader = TrackingReader::new(Input::from(buf));
+    assert_eq!(reader.read_u16be(), Ok(1));
+    let expected = concat!(
+        "00000002: 4865 6c6c 6f2c 2077 6f72 6c64 2120 486f  Hello, world! Ho\n",
+        "00000012: 7720 6172 6520 796f 753f                 w are you?\n",
+    );
+    assert_eq!(reader.remaining_hexdump(), expected);
+    assert_eq!(reader.position(), 2);
+}
+
+#[test]
+#[cfg(feature = "alloc")]
+fn read_offset_table_u32be() {
+    let buf = [0, 0, 0, 4, 0, 0, 0, 12, 0, 0, 0, 13];
+    let mut reader = TrackingReader::new(Input::from(&buf));
+    assert_eq!(reader.read_offset_table_u32be(2, true), Ok(vec![4, 12]));
+    assert_eq!(
+        reader.read_offset_table_u32be(1, true),
+        Err(Error::InvalidValue)
+    );
+
+    let mut reader = TrackingReader::new(Input::from(&buf));
+    assert_eq!(
+        reader.read_offset_table_u32be(3, false),
+        Ok(vec![4, 12, 13])
+    );
+    assert_eq!(
+        reader.read_offset_table_u32be(1, false),
+        Err(Error::EndOfInput)
+    );
+}
+
+#[test]
+fn read_bytes_exact() {
+    let buf = [1, 2, 3, 4, 5];
+    let mut reader = TrackingReader::new(Input::from(&buf));
+    let bytes = reader.read_bytes_exact(2).expect("read_bytes_exact");
+    assert_eq!(bytes.as_slice_less_safe(), &[1, 2]);
+    assert_eq!(
+        reader.read_bytes_exact(4).map(|_| ()),
+        Err(Error::Incomplete {
+            needed: 4,
+            available: 3
+        })
+    );
+    assert_eq!(reader.position(), 2);
+    assert_eq!(reader.read_u8(), Ok(3));
+}
+
+#[test]
+fn peek_uleb128_u64() {
+    let buf = [0xE5, 0x8E, 0x26, 0x80];
+    let mut reader = TrackingReader::new(Input::from(&buf));
+    assert_eq!(reader.peek_uleb128_u64(), Ok(624_485));
+    assert_eq!(reader.position(), 0);
+
+    let mut value = 0;
+    let read = reader.read_uleb128_generic(|shift, bits| {
+        value |= u64::from(bits) << shift;
+        Ok(())
+    });
+    assert_eq!(read, Ok(()));
+    assert_eq!(value, 624_485);
+    assert_eq!(reader.position(), 3);
+
+    assert_eq!(reader.peek_uleb128_u64(), Err(Error::EndOfInput));
+    assert_eq!(reader.position(), 3);
+}
+
+#[test]
+fn bytes_remaining() {
+    let buf = [1, 2, 3, 4, 5, 6, 7];
+    let mut reader = TrackingReader::new(Input::from(&buf));
+    assert_eq!(reader.bytes_remaining(), 7);
+    assert_eq!(reader.read_u8(), Ok(1));
+    assert_eq!(reader.bytes_remaining(), 6);
+    assert_eq!(reader.read_u32be(), Ok(0x0203_0405));
+    assert_eq!(reader.bytes_remaining(), 2);
+    assert!(reader.read_bytes(3).is_err());
+    assert_eq!(reader.bytes_remaining(), 2);
+    assert!(reader.read_bytes(2).is_ok());
+    assert_eq!(reader.bytes_remaining(), 0);
+    assert!(reader.at_end());
+}
+
+#[test]
+fn read_remaining() {
+    let buf = [1, 2, 3];
+    let mut reader = TrackingReader::new(Input::from(&buf));
+    assert_eq!(reader.read_u8(), Ok(1));
+    assert_eq!(reader.read_remaining(), Ok(&buf[1..]));
+    assert_eq!(reader.bytes_remaining(), 0);
+    assert_eq!(reader.position(), 3);
+    assert_eq!(reader.read_remaining(), Ok(&[][..]));
+}
+
+#[test]
+fn peek_u8_and_u16be() {
+    let buf = [0x12, 0x34, 0x56];
+    let mut reader = TrackingReader::new(Input::from(&buf));
+    assert_eq!(reader.peek_u8(), Ok(0x12));
+    assert_eq!(reader.peek_u16be(), Ok(0x1234));
+    assert_eq!(reader.position(), 0);
+    assert_eq!(reader.read_u16be(), Ok(0x1234));
+    assert_eq!(reader.peek_u16be(), Err(Error::EndOfInput));
+    assert_eq!(reader.peek_u8(), Ok(0x56));
+    assert_eq!(reader.position(), 2);
+    assert_eq!(reader.read_u8(), Ok(0x56));
+    assert_eq!(reader.peek_u8(), Err(Error::EndOfInput));
+}
+
+#[test]
+fn read_while_bounded() {
+    let buf = b"12345abc";
+    let mut reader = TrackingReader::new(Input::from(buf));
+    assert_eq!(
+        reader.read_while_bounded(3, |b| b.is_ascii_digit()),
+        Ok(&b"123"[..])
+    );
+    assert_eq!(reader.position(), 3);
+    assert_eq!(
+        reader.read_while_bounded(10, |b| b.is_ascii_digit()),
+        Ok(&b"45"[..])
+    );
+    assert_eq!(reader.position(), 5);
+    assert_eq!(reader.peek_u8(), Ok(b'a'));
+    assert_eq!(
+        reader.read_while_bounded(10, |b| b.is_ascii_alphabetic()),
+        Ok(&b"abc"[..])
+    );
+    assert!(reader.at_end());
+}
+
+#[test]
+fn read_while_bounded_long_run() {
+    let buf = [b'a'; 100_000];
+    let mut reader = TrackingReader::new(Input::from(&buf));
+    assert_eq!(reader.read_while_bounded(16, |b| b == b'a'), Ok(&buf[..16]));
+    assert_eq!(reader.position(), 16);
+    assert_eq!(
+        reader.read_while_bounded(usize::MAX, |b| b == b'a'),
+        Ok(&buf[16..])
+    );
+    assert!(reader.at_end());
+}
+
+#[test]
+fn read_aligned_be_truncated_padding() {
+    let buf = [0x01, 0x00];
+    let mut reader = TrackingReader::new(Input::from(&buf));
+    assert_eq!(reader.read_u8(), Ok(1));
+    assert_eq!(reader.read_aligned_be::<u64>(), Err(Error::EndOfInput));
+}
+
+#[test]
+fn align_to() {
+    let buf = [0u8; 32];
+    // (alignment, start_offset, bytes read before aligning, expected position)
+    let cases = [
+        (2, 0, 0, 0),
+        (2, 0, 1, 2),
+        (2, 1, 2, 3),
+        (4, 0, 1, 4),
+        (4, 0, 4, 4),
+        (4, 3, 4, 7),
+        (4, 2, 7, 10),
+        (8, 0, 1, 8),
+        (8, 5, 6, 13),
+        (8, 5, 13, 13),
+        (8, 3, 12, 19),
+    ];
+    for (alignment, start_offset, before, expected) in cases {
+        let mut reader = TrackingReader::new(Input::from(&buf));
+        reader.skip_bytes(before).expect("skip_bytes");
+        assert_eq!(reader.align_to(alignment, start_offset), Ok(()));
+        assert_eq!(
+            reader.position(),
+            expected,
+            "{alignment} {start_offset} {before}"
+        );
+    }
+}
+
+#[test]
+fn align_to_invalid() {
+    let buf = [0x01, 0x00, 0x00];
+    let mut reader = TrackingReader::new(Input::from(&buf));
+    assert_eq!(reader.read_u8(), Ok(1));
+    assert_eq!(reader.align_to(0, 0), Err(Error::ParseError));
+    assert_eq!(reader.align_to(6, 0), Err(Error::ParseError));
+    assert_eq!(reader.align_to(4, 2), Err(Error::ParseError));
+    assert_eq!(reader.align_to(8, 0), Err(Error::EndOfInput));
+    assert_eq!(reader.position(), 1);
+}
+
+#[test]
+fn read_all_with_offset() {
+    // Header of u16 length followed by a u32 and a truncated u32.
+    let buf = [0x00, 0x08, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00];
+    let read = |input: &mut TrackingReader<'_>| {
+        let len = input.read_u16be()?;
+        let first = input.read_u32be()?;
+        let second = input.read_u32be()?;
+        Ok((len, first, second))
+    };
+
+    let result = TrackingReader::read_all_with_offset(Input::from(&buf), Error::UnknownError, read);
+    assert_eq!(
+        result,
+        Err(OffsetError {
+            offset: 6,
+            error: Error::EndOfInput,
+        })
+    );
+
+    let mut complete = buf.to_vec();
+    complete.push(0x02);
+    let result =
+        TrackingReader::read_all_with_offset(Input::from(&complete), Error::UnknownError, read);
+    assert_eq!(result, Ok((8, 1, 2)));
+
+    complete.push(0xFF);
+    let result =
+        TrackingReader::read_all_with_offset(Input::from(&complete), Error::UnknownError, read);
+    assert_eq!(
+        result,
+        Err(OffsetError {
+            offset: 10,
+            error: Error::UnknownError,
+        })
+    );
+}
+
+#[test]
+fn read_length_prefixed_exceeds_input() {
+    let buf = [0x01, 0x03, 0xAA, 0xBB];
+    let mut reader = TrackingReader::new(Input::from(&buf));
+    assert_eq!(reader.read_u8(), Ok(0x01));
+    assert_eq!(
+        reader.read_length_prefixed_u8(),
+        Err(Error::LengthExceedsInput {
+            needed: 3,
+            available: 2
+        })
+    );
+    assert_eq!(reader.position(), 1);
+    assert_eq!(reader.read_u8(), Ok(0x03));
+
+    let buf = [0x00, 0x05, 0xDD];
+    let mut reader = TrackingReader::new(Input::from(&buf));
+    assert_eq!(
+        reader.read_length_prefixed_u16be(),
+        Err(Error::LengthExceedsInput {
+            needed: 5,
+            available: 1
+        })
+    );
+    assert_eq!(reader.position(), 0);
+
+    let buf = [0x00, 0x00, 0x01, 0x00];
+    let mut reader = TrackingReader::new(Input::from(&buf));
+    assert_eq!(
+        reader.read_length_prefixed_u32be(),
+        Err(Error::LengthExceedsInput {
+            needed: 256,
+            available: 0
+        })
+    );
+    assert_eq!(reader.position(), 0);
+
+    let buf = [0xFF, 0xFF, 0xFF, 0xFF, 0xAA];
+    let mut reader = TrackingReader::new(Input::from(&buf));
+    assert_eq!(
+        reader.read_length_prefixed_u32be(),
+        Err(Error::LengthExceedsInput {
+            needed: usize::try_from(u32::MAX).unwrap_or(usize::MAX),
+            available: 1
+        })
+    );
+    assert_eq!(reader.position(), 0);
+
+    let buf = [0x00];
+    let mut reader = TrackingReader::new(Input::from(&buf));
+    assert_eq!(reader.read_length_prefixed_u16be(), Err(Error::EndOfInput));
+    assert_eq!(reader.position(), 0);
+}
+
+#[test]
+fn read_length_prefixed_exceeds_input_offset() {
+    let buf = [0xAA, 0x00, 0x04, 0x01];
+    let result =
+        TrackingReader::read_all_with_offset(Input::from(&buf), Error::UnknownError, |reader| {
+            reader.read_u8()?;
+            reader.read_length_prefixed_u16be()
+        });
+    assert_eq!(
+        result,
+        Err(OffsetError {
+            offset: 1,
+            error: Error::LengthExceedsInput {
+                needed: 4,
+                available: 1
+            }
+        })
+    );
+}
@@ -0,0 +1,9 @@
+use untrustended::Readable;
+
+#[derive(Readable)]
+struct Header {
+    #[untrustended(network)]
+    length: u32,
+}
+
+fn main() {}
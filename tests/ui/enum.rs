@@ -0,0 +1,9 @@
+use untrustended::Readable;
+
+#[derive(Readable)]
+enum Kind {
+    A,
+    B,
+}
+
+fn main() {}
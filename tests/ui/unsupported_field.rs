@@ -0,0 +1,11 @@
+use untrustended::Readable;
+
+struct Opaque;
+
+#[derive(Readable)]
+struct Header {
+    length: u32,
+    body: Opaque,
+}
+
+fn main() {}
@@ -0,0 +1,302 @@
+#![deny(warnings)]
+#![cfg(feature = "alloc")]
+
+#[cfg(feature = "use_std")]
+use std::net::{Ipv4Addr, Ipv6Addr};
+
+use quickcheck::quickcheck;
+
+use untrusted::{Input, Reader};
+use untrustended::writer::{Writer, WriterExt};
+use untrustended::ReaderExt;
+
+#[inline]
+fn reader<'r>(buf: &'r [u8]) -> Reader<'r> {
+    Reader::new(Input::from(buf))
+}
+
+quickcheck! {
+    fn prop_write_read_u8(xs: u8) -> bool {
+        let mut writer = Writer::new();
+        writer.write_u8(xs);
+        reader(writer.as_slice()).read_u8() == Ok(xs)
+    }
+
+    fn prop_write_read_i8(xs: i8) -> bool {
+        let mut writer = Writer::new();
+        writer.write_i8(xs);
+        reader(writer.as_slice()).read_i8() == Ok(xs)
+    }
+
+    fn prop_write_read_u16be(xs: u16) -> bool {
+        let mut writer = Writer::new();
+        writer.write_u16be(xs);
+        reader(writer.as_slice()).read_u16be() == Ok(xs)
+    }
+
+    fn prop_write_read_u16le(xs: u16) -> bool {
+        let mut writer = Writer::new();
+        writer.write_u16le(xs);
+        reader(writer.as_slice()).read_u16le() == Ok(xs)
+    }
+
+    fn prop_write_read_u32be(xs: u32) -> bool {
+        let mut writer = Writer::new();
+        writer.write_u32be(xs);
+        reader(writer.as_slice()).read_u32be() == Ok(xs)
+    }
+
+    fn prop_write_read_u32le(xs: u32) -> bool {
+        let mut writer = Writer::new();
+        writer.write_u32le(xs);
+        reader(writer.as_slice()).read_u32le() == Ok(xs)
+    }
+
+    fn prop_write_read_u64be(xs: u64) -> bool {
+        let mut writer = Writer::new();
+        writer.write_u64be(xs);
+        reader(writer.as_slice()).read_u64be() == Ok(xs)
+    }
+
+    fn prop_write_read_u64le(xs: u64) -> bool {
+        let mut writer = Writer::new();
+        writer.write_u64le(xs);
+        reader(writer.as_slice()).read_u64le() == Ok(xs)
+    }
+
+    fn prop_write_read_i16be(xs: i16) -> bool {
+        let mut writer = Writer::new();
+        writer.write_i16be(xs);
+        reader(writer.as_slice()).read_i16be() == Ok(xs)
+    }
+
+    fn prop_write_read_i16le(xs: i16) -> bool {
+        let mut writer = Writer::new();
+        writer.write_i16le(xs);
+        reader(writer.as_slice()).read_i16le() == Ok(xs)
+    }
+
+    fn prop_write_read_i32be(xs: i32) -> bool {
+        let mut writer = Writer::new();
+        writer.write_i32be(xs);
+        reader(writer.as_slice()).read_i32be() == Ok(xs)
+    }
+
+    fn prop_write_read_i32le(xs: i32) -> bool {
+        let mut writer = Writer::new();
+        writer.write_i32le(xs);
+        reader(writer.as_slice()).read_i32le() == Ok(xs)
+    }
+
+    fn prop_write_read_i64be(xs: i64) -> bool {
+        let mut writer = Writer::new();
+        writer.write_i64be(xs);
+        reader(writer.as_slice()).read_i64be() == Ok(xs)
+    }
+
+    fn prop_write_read_i64le(xs: i64) -> bool {
+        let mut writer = Writer::new();
+        writer.write_i64le(xs);
+        reader(writer.as_slice()).read_i64le() == Ok(xs)
+    }
+
+    fn prop_write_read_u128be(xs: (u64, u64)) -> bool {
+        let ys = (u128::from(xs.0) << 64) | u128::from(xs.1);
+        let mut writer = Writer::new();
+        writer.write_u128be(ys);
+        reader(writer.as_slice()).read_u128be() == Ok(ys)
+    }
+
+    fn prop_write_read_u128le(xs: (u64, u64)) -> bool {
+        let ys = (u128::from(xs.0) << 64) | u128::from(xs.1);
+        let mut writer = Writer::new();
+        writer.write_u128le(ys);
+        reader(writer.as_slice()).read_u128le() == Ok(ys)
+    }
+
+    fn prop_write_read_i128be(xs: (u64, u64)) -> bool {
+        let ys = (i128::from(xs.0) << 64) | i128::from(xs.1);
+        let mut writer = Writer::new();
+        writer.write_i128be(ys);
+        reader(writer.as_slice()).read_i128be() == Ok(ys)
+    }
+
+    fn prop_write_read_i128le(xs: (u64, u64)) -> bool {
+        let ys = (i128::from(xs.0) << 64) | i128::from(xs.1);
+        let mut writer = Writer::new();
+        writer.write_i128le(ys);
+        reader(writer.as_slice()).read_i128le() == Ok(ys)
+    }
+
+    fn prop_write_read_f32be(xs: f32) -> bool {
+        let mut writer = Writer::new();
+        writer.write_f32be(xs);
+        reader(writer.as_slice()).read_f32be().map(f32::to_bits) == Ok(xs.to_bits())
+    }
+
+    fn prop_write_read_f32le(xs: f32) -> bool {
+        let mut writer = Writer::new();
+        writer.write_f32le(xs);
+        reader(writer.as_slice()).read_f32le().map(f32::to_bits) == Ok(xs.to_bits())
+    }
+
+    fn prop_write_read_f64be(xs: f64) -> bool {
+        let mut writer = Writer::new();
+        writer.write_f64be(xs);
+        reader(writer.as_slice()).read_f64be().map(f64::to_bits) == Ok(xs.to_bits())
+    }
+
+    fn prop_write_read_f64le(xs: f64) -> bool {
+        let mut writer = Writer::new();
+        writer.write_f64le(xs);
+        reader(writer.as_slice()).read_f64le().map(f64::to_bits) == Ok(xs.to_bits())
+    }
+
+    #[cfg(feature = "use_std")]
+    fn prop_write_read_ipv4addr(xs: u32) -> bool {
+        let addr = Ipv4Addr::from(xs);
+        let mut writer = Writer::new();
+        writer.write_ipv4addr(addr);
+        reader(writer.as_slice()).read_ipv4addr() == Ok(addr)
+    }
+
+    #[cfg(feature = "use_std")]
+    fn prop_write_read_ipv6addr(xs: (u64, u64)) -> bool {
+        let addr = Ipv6Addr::from((u128::from(xs.0) << 64) | u128::from(xs.1));
+        let mut writer = Writer::new();
+        writer.write_ipv6addr(addr);
+        reader(writer.as_slice()).read_ipv6addr() == Ok(addr)
+    }
+
+    fn prop_write_read_u24be(xs: u32) -> bool {
+        let xs = xs & 0xFF_FFFF;
+        let mut writer = Writer::new();
+        writer.write_u24be(xs);
+        reader(writer.as_slice()).read_u24be() == Ok(xs)
+    }
+
+    fn prop_write_read_u24le(xs: u32) -> bool {
+        let xs = xs & 0xFF_FFFF;
+        let mut writer = Writer::new();
+        writer.write_u24le(xs);
+        reader(writer.as_slice()).read_u24le() == Ok(xs)
+    }
+
+    fn prop_write_read_u48be(xs: u64) -> bool {
+        let xs = xs & 0xFFFF_FFFF_FFFF;
+        let mut writer = Writer::new();
+        writer.write_u48be(xs);
+        reader(writer.as_slice()).read_u48be() == Ok(xs)
+    }
+
+    fn prop_write_read_u48le(xs: u64) -> bool {
+        let xs = xs & 0xFFFF_FFFF_FFFF;
+        let mut writer = Writer::new();
+        writer.write_u48le(xs);
+        reader(writer.as_slice()).read_u48le() == Ok(xs)
+    }
+
+    fn prop_write_read_i24be(xs: i32) -> bool {
+        let xs = (xs << 8) >> 8;
+        let mut writer = Writer::new();
+        writer.write_i24be(xs);
+        reader(writer.as_slice()).read_i24be() == Ok(xs)
+    }
+
+    fn prop_write_read_i24le(xs: i32) -> bool {
+        let xs = (xs << 8) >> 8;
+        let mut writer = Writer::new();
+        writer.write_i24le(xs);
+        reader(writer.as_slice()).read_i24le() == Ok(xs)
+    }
+
+    fn prop_write_read_i48be(xs: i64) -> bool {
+        let xs = (xs << 16) >> 16;
+        let mut writer = Writer::new();
+        writer.write_i48be(xs);
+        reader(writer.as_slice()).read_i48be() == Ok(xs)
+    }
+
+    fn prop_write_read_i48le(xs: i64) -> bool {
+        let xs = (xs << 16) >> 16;
+        let mut writer = Writer::new();
+        writer.write_i48le(xs);
+        reader(writer.as_slice()).read_i48le() == Ok(xs)
+    }
+
+    fn prop_write_read_uleb128(xs: u64) -> bool {
+        let mut writer = Writer::new();
+        writer.write_uleb128(xs);
+        let mut reader = reader(writer.as_slice());
+        reader.read_uleb128() == Ok(xs) && reader.at_end()
+    }
+
+    fn prop_write_read_sleb128(xs: i64) -> bool {
+        let mut writer = Writer::new();
+        writer.write_sleb128(xs);
+        let mut reader = reader(writer.as_slice());
+        reader.read_sleb128() == Ok(xs) && reader.at_end()
+    }
+
+    fn prop_write_read_vlq_u32(xs: u32) -> bool {
+        let xs = xs & 0x0FFF_FFFF;
+        let mut writer = Writer::new();
+        writer.write_vlq_u32(xs);
+        let mut reader = reader(writer.as_slice());
+        reader.read_vlq_u32() == Ok(xs) && reader.at_end()
+    }
+
+    fn prop_write_read_vlq_i32(xs: i32) -> bool {
+        let xs = (xs << 4) >> 4;
+        let mut writer = Writer::new();
+        writer.write_vlq_i32(xs);
+        let mut reader = reader(writer.as_slice());
+        reader.read_vlq_i32() == Ok(xs) && reader.at_end()
+    }
+
+    fn prop_write_read_bytes(xs: Vec<u8>) -> bool {
+        let mut writer = Writer::new();
+        writer.write_bytes(&xs);
+        reader(writer.as_slice()).read_bytes_less_safe(xs.len()) == Ok(&xs[..])
+    }
+}
+
+#[test]
+fn writer() {
+    let mut writer = Writer::with_capacity(16);
+    assert!(writer.is_empty());
+    writer.write_u8(0x01);
+    writer.write_u16be(0x0203);
+    writer.write_u16le(0x0504);
+    writer.write_i32be(-2);
+    writer.write_bytes(b"ok");
+    assert_eq!(writer.len(), 11);
+    assert_eq!(
+        writer.into_vec(),
+        [0x01, 0x02, 0x03, 0x04, 0x05, 0xFF, 0xFF, 0xFF, 0xFE, b'o', b'k']
+    );
+}
+
+#[test]
+fn write_varints() {
+    let mut writer = Writer::new();
+    writer.write_uleb128(624_485);
+    writer.write_sleb128(-123_456);
+    writer.write_sleb128(63);
+    writer.write_sleb128(64);
+    writer.write_vlq_u32(0x3FFF);
+    writer.write_vlq_i32(-1);
+    writer.write_vlq_i32(64);
+    assert_eq!(
+        writer.into_vec(),
+        [
+            0xE5, 0x8E, 0x26, // uleb128
+            0xC0, 0xBB, 0x78, // sleb128
+            0x3F, // sleb128
+            0xC0, 0x00, // sleb128
+            0xFF, 0x7F, // vlq_u32
+            0x7F, // vlq_i32
+            0x80, 0x40, // vlq_i32
+        ]
+    );
+}
@@ -0,0 +1,161 @@
+//! # Untrustended Derive
+//!
+//! Derive macro for [untrustended](https://crates.io/crates/untrustended)'s
+//! `Readable` trait. Use it through untrustended's `derive` feature:
+//!
+//! ```toml
+//! [dependencies]
+//! untrustended = { version = "0.4", features = [ "derive" ] }
+//! ```
+//!
+//! See [`Readable`](derive@Readable) for details.
+
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{quote, quote_spanned};
+use syn::spanned::Spanned;
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Error, Fields};
+
+/// Derives `Readable` for a struct with `Output = Self`.
+///
+/// Fields are read in declaration order with the field type's `FromReader`
+/// implementation. Fields are read in Big Endian byte order unless
+/// `#[untrustended(le)]` is given on the struct or on the field. Attribute on
+/// a field takes precedence over one on the struct.
+///
+/// `Readable::read` uses the byte order of the struct. `Readable::read_be`
+/// and `Readable::read_le` use the given byte order for fields without an
+/// attribute of their own.
+///
+/// Example:
+///
+/// ```rust,ignore
+/// use untrustended::Readable;
+///
+/// #[derive(Readable)]
+/// #[untrustended(le)]
+/// struct Header {
+///     version: u8,
+///     flags: u16,
+///     #[untrustended(be)]
+///     length: u32,
+/// }
+/// ```
+///
+/// Deriving for an enum or a union, or for a struct with a field type not
+/// implementing `FromReader`, is a compile error.
+#[proc_macro_derive(Readable, attributes(untrustended))]
+pub fn derive_readable(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand(&input)
+        .unwrap_or_else(Error::into_compile_error)
+        .into()
+}
+
+#[derive(Clone, Copy)]
+enum Endianness {
+    Big,
+    Little,
+}
+
+fn expand(input: &DeriveInput) -> Result<TokenStream2, Error> {
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        Data::Enum(data) => {
+            return Err(Error::new(
+                data.enum_token.span,
+                "Readable can only be derived for structs",
+            ))
+        }
+        Data::Union(data) => {
+            return Err(Error::new(
+                data.union_token.span,
+                "Readable can only be derived for structs",
+            ))
+        }
+    };
+
+    let default = endianness(&input.attrs)?.unwrap_or(Endianness::Big);
+    let mut field_endianness = Vec::new();
+    for field in fields {
+        field_endianness.push(endianness(&field.attrs)?);
+    }
+
+    let body = |fallback: Endianness| {
+        let values = fields.iter().zip(&field_endianness).map(|(field, e)| {
+            let method = match e.unwrap_or(fallback) {
+                Endianness::Big => quote!(read_be),
+                Endianness::Little => quote!(read_le),
+            };
+            let ty = &field.ty;
+            let value = quote_spanned! {ty.span()=>
+                <#ty as ::untrustended::FromReader>::#method(input)?
+            };
+            match &field.ident {
+                Some(ident) => quote!(#ident: #value),
+                None => value,
+            }
+        });
+        match fields {
+            Fields::Named(_) => quote!(Ok(Self { #(#values),* })),
+            Fields::Unnamed(_) => quote!(Ok(Self(#(#values),*))),
+            Fields::Unit => quote!(Ok(Self)),
+        }
+    };
+    let read = body(default);
+    let read_be = body(Endianness::Big);
+    let read_le = body(Endianness::Little);
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics ::untrustended::Readable for #name #ty_generics #where_clause {
+            type Output = Self;
+
+            fn read(
+                input: &mut ::untrusted::Reader<'_>,
+            ) -> ::core::result::Result<Self, ::untrustended::Error> {
+                #read
+            }
+
+            fn read_be(
+                input: &mut ::untrusted::Reader<'_>,
+            ) -> ::core::result::Result<Self, ::untrustended::Error> {
+                #read_be
+            }
+
+            fn read_le(
+                input: &mut ::untrusted::Reader<'_>,
+            ) -> ::core::result::Result<Self, ::untrustended::Error> {
+                #read_le
+            }
+        }
+    })
+}
+
+/// Parses `#[untrustended(be)]` or `#[untrustended(le)]` from attributes.
+fn endianness(attrs: &[Attribute]) -> Result<Option<Endianness>, Error> {
+    let mut found: Option<(Endianness, Span)> = None;
+    for attr in attrs {
+        if !attr.path().is_ident("untrustended") {
+            continue;
+        }
+        attr.parse_nested_meta(|meta| {
+            let endianness = if meta.path.is_ident("be") {
+                Endianness::Big
+            } else if meta.path.is_ident("le") {
+                Endianness::Little
+            } else {
+                return Err(meta.error("expected `be` or `le`"));
+            };
+            if let Some((_, span)) = found {
+                let mut err = meta.error("byte order given more than once");
+                err.combine(Error::new(span, "byte order first given here"));
+                return Err(err);
+            }
+            found = Some((endianness, meta.path.span()));
+            Ok(())
+        })?;
+    }
+    Ok(found.map(|(endianness, _)| endianness))
+}